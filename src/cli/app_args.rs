@@ -9,10 +9,18 @@ pub use crate::cli::{
     list_command::ListCommand,
     month_list_command::ListByMonthCommand,
     deletion_arguments::{DeleteDateArg, DeleteYearArg, DeleteMonthArg},
+    trash_command::TrashCommand,
+    migrate_command::MigrateArg,
+    doctor_command::DoctorArg,
+    config_command::{ConfigCommand, PlaceholderCommand},
+    append_command::AppendArg,
+    write_command::WriteArg,
+    path_command::{PathArg, JournalSelectionArg},
+    on_this_day_command::OnThisDayArg,
+    cat_command::CatArg,
+    section_command::SectionArg,
 };
 
-use crate::cli::build_env_name;
-
 use super::edit_command::EditCommandAndArgs;
 use super::edit_year::EditByYear;
 use super::month_edit_command::EditByMonthCommandAndArgs;
@@ -64,6 +72,50 @@ pub enum AppCommands {
     #[command(visible_alias = "yl")]
     /// Lists all created journal for a year.
     YearList,
+    #[command(subcommand, visible_alias = "t")]
+    /// Lists, restores or purges deleted journals. Deleted journals are kept in the trash
+    /// folder inside the data folder.
+    Trash(TrashCommand),
+    #[command(visible_alias = "mg")]
+    /// Moves and renames existing journals according to the configured naming patterns and
+    /// layout of the data folder. Nothing is moved if there are conflicts.
+    Migrate(MigrateArg),
+    /// Checks the data folder for files which are not recognized as journals, almost correctly
+    /// named journals, invalid dates, duplicates, empty journals and unreadable entries.
+    /// Offers a fix for every found problem.
+    Doctor(DoctorArg),
+    #[command(subcommand, visible_alias = "c")]
+    /// Checks the config file.
+    Config(ConfigCommand),
+    #[command(visible_alias = "nb")]
+    /// Lists the notebooks of the config file with the number of their journals.
+    Notebooks,
+    #[command(visible_alias = "a", alias = "log")]
+    /// Appends text to the journal of the selected day without opening an editor.
+    /// The date is selected like with edit. A missing journal is created from its template first.
+    Append(AppendArg),
+    #[command(visible_alias = "w")]
+    /// Writes the content from stdin into the journal of the selected day without an editor.
+    /// The content replaces the journal unless --append or --prepend is given.
+    /// A journal with content is only replaced with --force.
+    Write(WriteArg),
+    #[command(visible_alias = "p")]
+    /// Prints the absolute path of the journal of the selected day.
+    /// For a missing journal the path is printed which a new journal would get.
+    Path(PathArg),
+    /// Signals via exit code if the journal of the selected day exists.
+    /// Exits with 0 if it exists, with 1 if not and with 2 on an error. Nothing is printed.
+    Exists(JournalSelectionArg),
+    #[command(visible_alias = "otd")]
+    /// Shows the daily journals of the same calendar day in all previous years.
+    /// The day is selected like with edit. Shows today's journals of past years by default.
+    OnThisDay(OnThisDayArg),
+    /// Prints all journals within a range of dates in chronological order.
+    /// Every journal is preceded by a header with its date and kind.
+    Cat(CatArg),
+    /// Prints the content under the given heading of every journal within a range of dates.
+    /// The output is grouped by the date of the journals. Journals without the heading are left out.
+    Section(SectionArg),
 }
 
 #[derive(Args, CopyGetters, Clone)]
@@ -80,7 +132,7 @@ pub struct DebugArgs {
     run_editor_dry: bool,
 }
 
-#[derive(Args, CopyGetters, Getters, Clone, Default, new)]
+#[derive(Args, CopyGetters, Getters, Setters, Clone, Default, new)]
 pub struct GenerellArgs {
     #[getset(get_copy = "pub")]
     #[arg(short, long)]
@@ -94,4 +146,15 @@ pub struct GenerellArgs {
     #[getset(get = "pub")]
     /// Uses provided path to find folder with saved journals
     data_path: Option<String>,
+    #[arg(long, env = build_env_name!(STRICT_CONFIG))]
+    #[getset(get_copy = "pub", set = "pub")]
+    #[new(default)]
+    /// If true, then an invalid config file is an error instead of being ignored with a warning
+    strict_config: bool,
+    #[arg(long, env = build_env_name!(NOTEBOOK))]
+    #[getset(get = "pub", set = "pub")]
+    #[new(default)]
+    /// Name of the notebook from the config file to use.
+    /// If not given then the default notebook of the config file is used if there is one.
+    notebook: Option<String>,
 }
@@ -0,0 +1,28 @@
+use clap::Args;
+
+use super::edit_command::EditCommand;
+use super::journal_kind_arg::JournalKindArg;
+
+#[derive(Args, Getters, CopyGetters, Default)]
+pub struct AppendArg {
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    date: EditCommand,
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    kind: JournalKindArg,
+    #[arg(short, long)]
+    #[getset(get = "pub")]
+    /// Text to append. If not given then the text is read from stdin.
+    message: Option<String>,
+    #[arg(short, long)]
+    #[getset(get_copy = "pub")]
+    /// If given then the appended text starts with the current time like "14:05 ".
+    timestamp: bool,
+    #[arg(long)]
+    #[getset(get = "pub")]
+    /// Heading under which the text is appended. Example: "## Log"
+    /// Overrides the option append_section of the config file.
+    /// Without any heading the text is appended at the end of the journal.
+    section: Option<String>,
+}
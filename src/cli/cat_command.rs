@@ -0,0 +1,82 @@
+use chrono::NaiveDate;
+use clap::{Args, ValueEnum};
+
+use crate::core::date_models::find_by::{FindByRange, FindByYearMonthDay};
+use crate::core::date_models::JournalKind;
+use crate::AppResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+/// Kinds of journals which are selected.
+pub enum CatKind {
+    #[default]
+    Daily,
+    Monthly,
+    Yearly,
+    All,
+}
+
+impl CatKind {
+    pub fn to_kinds(self) -> Vec<JournalKind> {
+        match self {
+            Self::Daily => vec![JournalKind::Daily],
+            Self::Monthly => vec![JournalKind::Monthly],
+            Self::Yearly => vec![JournalKind::Yearly],
+            Self::All => vec![
+                JournalKind::Yearly,
+                JournalKind::Monthly,
+                JournalKind::Daily,
+            ],
+        }
+    }
+}
+
+#[derive(Args, Getters, CopyGetters, Default)]
+pub struct CatArg {
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    range: JournalRangeArg,
+    #[arg(long)]
+    #[getset(get = "pub")]
+    /// Header printed before every journal. {date}, {kind} and {name} are replaced by the date,
+    /// kind and file name of the journal. Overrides the option cat_header of the config file.
+    header: Option<String>,
+}
+
+#[derive(Args, Getters, CopyGetters, Default)]
+/// Selects journals within a range of dates.
+pub struct JournalRangeArg {
+    #[arg(long)]
+    /// Selects journals from this date on. Format: YYYY-MM-DD.
+    /// Monthly and yearly journals are selected if their month or year overlaps with the range.
+    from: Option<NaiveDate>,
+    #[arg(long)]
+    /// Selects journals up to this date. Format: YYYY-MM-DD.
+    to: Option<NaiveDate>,
+    #[arg(short, long)]
+    /// Selects only journals within the given year
+    year: Option<u32>,
+    #[arg(short, long)]
+    /// Selects only journals within the given month of any year
+    month: Option<u32>,
+    #[arg(short, long)]
+    /// Selects only daily journals on the given day of any month
+    day_of_month: Option<u32>,
+    #[arg(short, long, value_enum, default_value = "daily")]
+    #[getset(get_copy = "pub")]
+    /// Kind of journals to select
+    kind: CatKind,
+    #[arg(short, long)]
+    #[getset(get_copy = "pub")]
+    /// If given then the journals are printed from the newest to the oldest one
+    reverse: bool,
+}
+
+impl JournalRangeArg {
+    pub fn to_range(&self) -> FindByRange<NaiveDate> {
+        FindByRange::new(self.from, self.to)
+    }
+
+    pub fn to_date_filter(&self) -> AppResult<FindByYearMonthDay> {
+        FindByYearMonthDay::new(self.year, self.month, self.day_of_month)
+    }
+}
@@ -0,0 +1,80 @@
+use clap::{Args, Subcommand};
+use derive_new::new;
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Checks the config file for syntax errors, unknown keys, missing template files
+    /// and placeholders with duplicate keys.
+    #[command(visible_alias = "c")]
+    Check,
+    /// Writes a commented config file and starter templates for daily, monthly and yearly
+    /// journals into the config folder. Existing files are left alone.
+    #[command(visible_alias = "i")]
+    Init {
+        #[arg(short, long)]
+        /// If true, then existing config file and templates are overwritten.
+        force: bool,
+    },
+    /// Prints the configuration used by the app and where every value comes from:
+    /// CLI, env, file or default.
+    #[command(visible_alias = "s")]
+    Show,
+    /// Prints the value of one option in the config file.
+    #[command(visible_alias = "g")]
+    Get(ConfigGetArg),
+    /// Changes the value of one option in the config file. Comments and layout of the config file
+    /// are kept. The config file is created if it does not exist.
+    Set(ConfigSetArg),
+    /// Adds or removes placeholders in the config file.
+    #[command(subcommand, visible_alias = "p")]
+    Placeholder(PlaceholderCommand),
+}
+
+#[derive(Args, Getters, new)]
+#[getset(get = "pub")]
+pub struct ConfigGetArg {
+    /// Name of the option. Options within a table are separated by a dot.
+    /// Example: editor or encryption.key_file
+    key: String,
+}
+
+#[derive(Args, Getters, new)]
+#[getset(get = "pub")]
+pub struct ConfigSetArg {
+    /// Name of the option. Options within a table are separated by a dot.
+    /// Example: editor or encryption.key_file
+    key: String,
+    /// New value of the option.
+    value: String,
+}
+
+#[derive(Subcommand)]
+pub enum PlaceholderCommand {
+    /// Adds a placeholder. Replaces the value of an existing placeholder with the same key.
+    #[command(visible_alias = "a")]
+    Add(PlaceholderAddArg),
+    /// Removes the placeholder with the given key.
+    #[command(visible_alias = "r")]
+    Remove(PlaceholderRemoveArg),
+}
+
+#[derive(Args, Getters, CopyGetters, new)]
+pub struct PlaceholderAddArg {
+    #[getset(get = "pub")]
+    /// Key which is searched for in templates.
+    key: String,
+    #[getset(get = "pub")]
+    /// Value by which the key is replaced in templates.
+    value: String,
+    #[arg(short = 'c', long)]
+    #[getset(get_copy = "pub")]
+    /// If true, then the value is executed as command and its output is used instead.
+    is_command: bool,
+}
+
+#[derive(Args, Getters, new)]
+#[getset(get = "pub")]
+pub struct PlaceholderRemoveArg {
+    /// Key of the placeholder to remove.
+    key: String,
+}
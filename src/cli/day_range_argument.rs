@@ -0,0 +1,131 @@
+use chrono::{Datelike, Days, NaiveDate};
+use clap::Args;
+use date_validation_types::ValidatedDate;
+
+use crate::core::date_models::open_by::OpenByDaysInTime;
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Bound of a range of days given as date or as number of days relative to today.
+pub enum DayBound {
+    Date(NaiveDate),
+    Relative(i32),
+}
+
+impl DayBound {
+    fn to_date(self, today: ValidatedDate) -> AppResult<ValidatedDate> {
+        match self {
+            Self::Date(date) => Ok(date.into()),
+            Self::Relative(days) => OpenByDaysInTime::new(days).from_point_in_time(today),
+        }
+    }
+}
+
+#[derive(Args, Default)]
+#[group(multiple = true, conflicts_with_all = ["NavigateArg", "show_only"])]
+pub struct DayRangeArg {
+    #[arg(
+        long,
+        value_parser = parse_day_bound,
+        allow_negative_numbers = true,
+        conflicts_with_all = ["range_or_year", "day_of_year_or_month", "day_of_month", "week"]
+    )]
+    /// Opens all daily journals from this day on in one editor session.
+    /// Given as date in the format YYYY-MM-DD or as number of days relative to today like -6.
+    /// Can be combined with --to. Today is used if only --to is given.
+    from: Option<DayBound>,
+    #[arg(
+        long,
+        value_parser = parse_day_bound,
+        allow_negative_numbers = true,
+        conflicts_with_all = ["range_or_year", "day_of_year_or_month", "day_of_month", "week"]
+    )]
+    /// Opens all daily journals up to this day in one editor session.
+    /// Given as date in the format YYYY-MM-DD or as number of days relative to today like 0.
+    /// Can be combined with --from. Today is used if only --from is given.
+    to: Option<DayBound>,
+    #[arg(long)]
+    /// Opens all daily journals of the week of the selected day in one editor session.
+    /// A week starts on monday.
+    week: bool,
+}
+
+impl DayRangeArg {
+    /// Returns the first and last day of the range.
+    /// Relative bounds count from `today`. The week is taken around the `selected` day.
+    /// Returns none if no range was given.
+    pub fn to_days(
+        &self,
+        today: ValidatedDate,
+        selected: ValidatedDate,
+    ) -> AppResult<Option<(ValidatedDate, ValidatedDate)>> {
+        if self.week {
+            let selected: NaiveDate = selected.into();
+            let from_monday = selected.weekday().num_days_from_monday() as u64;
+            let monday = selected
+                .checked_sub_days(Days::new(from_monday))
+                .ok_or_else(|| anyhow!("There is no week for the day {}", selected))?;
+            let sunday = monday
+                .checked_add_days(Days::new(6))
+                .ok_or_else(|| anyhow!("There is no week for the day {}", selected))?;
+            return Ok(Some((monday.into(), sunday.into())));
+        }
+
+        if self.from.is_none() && self.to.is_none() {
+            return Ok(None);
+        }
+
+        let from = self.from.map_or(Ok(today), |from| from.to_date(today))?;
+        let to = self.to.map_or(Ok(today), |to| to.to_date(today))?;
+
+        Ok(Some((from, to)))
+    }
+}
+
+fn parse_day_bound(to_parse: &str) -> Result<DayBound, String> {
+    let to_parse = to_parse.trim();
+    if let Ok(days) = to_parse.parse() {
+        return Ok(DayBound::Relative(days));
+    }
+
+    to_parse.parse().map(DayBound::Date).map_err(|_| {
+        format!("{to_parse} is neither a date in the format YYYY-MM-DD nor a number of days")
+    })
+}
+
+#[cfg(test)]
+mod testing {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("-6" => Ok(DayBound::Relative(-6)) ; "Should parse days in the past")]
+    #[test_case("0" => Ok(DayBound::Relative(0)) ; "Should parse today")]
+    #[test_case("2023-03-10" => Ok(DayBound::Date(NaiveDate::from_ymd_opt(2023, 3, 10).unwrap())) ; "Should parse date")]
+    #[test_case("2023-13-10" => matches Err(_) ; "Should reject invalid date")]
+    fn should_parse_day_bound(given: &str) -> Result<DayBound, String> {
+        parse_day_bound(given)
+    }
+
+    #[test_case(None, None, false => None ; "Should return none without range")]
+    #[test_case(Some(DayBound::Relative(-6)), Some(DayBound::Relative(0)), false => Some(("2023-03-04".to_owned(), "2023-03-10".to_owned())) ; "Should count relative bounds from today")]
+    #[test_case(Some(DayBound::Relative(-2)), None, false => Some(("2023-03-08".to_owned(), "2023-03-10".to_owned())) ; "Should end today without to")]
+    #[test_case(None, Some(DayBound::Date(NaiveDate::from_ymd_opt(2023, 3, 12).unwrap())), false => Some(("2023-03-10".to_owned(), "2023-03-12".to_owned())) ; "Should start today without from")]
+    #[test_case(None, None, true => Some(("2023-02-27".to_owned(), "2023-03-05".to_owned())) ; "Should select week of selected day from monday to sunday")]
+    fn should_return_days_of_range(
+        from: Option<DayBound>,
+        to: Option<DayBound>,
+        week: bool,
+    ) -> Option<(String, String)> {
+        let today = ValidatedDate::from_ymd(2023, 3, 10).unwrap();
+        let selected = ValidatedDate::from_ymd(2023, 3, 1).unwrap();
+        let given = DayRangeArg { from, to, week };
+
+        given.to_days(today, selected).unwrap().map(|(from, to)| {
+            (
+                NaiveDate::from(from).to_string(),
+                NaiveDate::from(to).to_string(),
+            )
+        })
+    }
+}
@@ -1,6 +1,10 @@
+use chrono::NaiveDate;
 use clap::Args;
+use date_validation_types::{ValidatedDate, ValidatedMonth, ValidatedYear};
 use derive_new::new;
 use super::app_args::{EditByMonthCommand, EditCommand};
+use crate::core::date_models::find_by::FindByRange;
+use crate::prelude::*;
 
 #[derive(Args, CopyGetters, Getters)]
 pub struct DeleteDateArg {
@@ -9,6 +13,9 @@ pub struct DeleteDateArg {
     date: EditCommand,
     #[command(flatten)]
     #[getset(get = "pub")]
+    range: DeleteDailyRangeArg,
+    #[command(flatten)]
+    #[getset(get = "pub")]
     common_arg: CommonDeleteArg,
 }
 #[derive(Args, CopyGetters, Getters)]
@@ -18,13 +25,20 @@ pub struct DeleteMonthArg {
     month: EditByMonthCommand,
     #[command(flatten)]
     #[getset(get = "pub")]
+    range: DeleteMonthlyRangeArg,
+    #[command(flatten)]
+    #[getset(get = "pub")]
     common_arg: CommonDeleteArg,
 }
 #[derive(Args, CopyGetters, Getters, new)]
 pub struct DeleteYearArg {
     /// which year journal to delete
+    #[arg(required_unless_present_any = ["from", "to"])]
+    #[getset(get_copy = "pub")]
+    year: Option<u32>,
+    #[command(flatten)]
     #[getset(get = "pub")]
-    year: u32,
+    range: DeleteYearlyRangeArg,
     #[command(flatten)]
     #[getset(get = "pub")]
     common_arg: CommonDeleteArg,
@@ -36,4 +50,146 @@ pub struct CommonDeleteArg {
     #[getset(get_copy = "pub")]
     /// If provided then no prompt will show up for confirmation before a deletion
     skip_confirmation: bool,
+    #[arg(long)]
+    #[getset(get_copy = "pub")]
+    /// If provided then the journals to be deleted are only listed without deleting them
+    dry_run: bool,
+}
+
+#[derive(Args, Default, new)]
+pub struct DeleteDailyRangeArg {
+    #[arg(long, conflicts_with_all = ["range_or_year", "day_of_year_or_month", "day_of_month"])]
+    /// Deletes all daily journals from this date on. Format: YYYY-MM-DD.
+    /// Can be combined with --to.
+    from: Option<NaiveDate>,
+    #[arg(long, conflicts_with_all = ["range_or_year", "day_of_year_or_month", "day_of_month"])]
+    /// Deletes all daily journals up to this date. Format: YYYY-MM-DD.
+    /// Can be combined with --from.
+    to: Option<NaiveDate>,
+}
+
+impl DeleteDailyRangeArg {
+    /// Returns none if no bound of a range was given.
+    pub fn to_range(&self) -> Option<FindByRange<ValidatedDate>> {
+        if self.from.is_none() && self.to.is_none() {
+            return None;
+        }
+
+        Some(FindByRange::new(
+            self.from.map(ValidatedDate::from),
+            self.to.map(ValidatedDate::from),
+        ))
+    }
+}
+
+#[derive(Args, Default, new)]
+pub struct DeleteMonthlyRangeArg {
+    #[arg(long, conflicts_with_all = ["month", "year", "from", "to"])]
+    /// Deletes all monthly journals within the given year.
+    in_year: Option<u32>,
+    #[arg(long, value_parser = parse_year_month, conflicts_with_all = ["month", "year"])]
+    /// Deletes all monthly journals from this month on. Format: YYYY-MM.
+    /// Can be combined with --to.
+    from: Option<(u32, u32)>,
+    #[arg(long, value_parser = parse_year_month, conflicts_with_all = ["month", "year"])]
+    /// Deletes all monthly journals up to this month. Format: YYYY-MM.
+    /// Can be combined with --from.
+    to: Option<(u32, u32)>,
+}
+
+type ValidatedYearMonth = (ValidatedYear, ValidatedMonth);
+
+impl DeleteMonthlyRangeArg {
+    /// Returns none if no bound of a range was given.
+    pub fn to_range(&self) -> AppResult<Option<FindByRange<ValidatedYearMonth>>> {
+        if let Some(year) = self.in_year {
+            let from = validate_year_month((year, 1))?;
+            let to = validate_year_month((year, 12))?;
+            return Ok(Some(FindByRange::new(Some(from), Some(to))));
+        }
+
+        if self.from.is_none() && self.to.is_none() {
+            return Ok(None);
+        }
+
+        let from = self.from.map(validate_year_month).transpose()?;
+        let to = self.to.map(validate_year_month).transpose()?;
+
+        Ok(Some(FindByRange::new(from, to)))
+    }
+}
+
+fn validate_year_month((year, month): (u32, u32)) -> AppResult<ValidatedYearMonth> {
+    let year: ValidatedYear = year.try_into()?;
+    let month: ValidatedMonth = month.try_into()?;
+    Ok((year, month))
+}
+
+fn parse_year_month(to_parse: &str) -> Result<(u32, u32), String> {
+    let on_error = || format!("{to_parse} is not in the format YYYY-MM");
+    let (year, month) = to_parse.trim().split_once('-').ok_or_else(on_error)?;
+    let year: u32 = year.parse().map_err(|_| on_error())?;
+    let month: u32 = month.parse().map_err(|_| on_error())?;
+
+    Ok((year, month))
+}
+
+#[derive(Args, Default, new)]
+pub struct DeleteYearlyRangeArg {
+    #[arg(long, conflicts_with = "year")]
+    /// Deletes all yearly journals from this year on. Can be combined with --to.
+    from: Option<u32>,
+    #[arg(long, conflicts_with = "year")]
+    /// Deletes all yearly journals up to this year. Can be combined with --from.
+    to: Option<u32>,
+}
+
+impl DeleteYearlyRangeArg {
+    /// Returns none if no bound of a range was given.
+    pub fn to_range(&self) -> AppResult<Option<FindByRange<ValidatedYear>>> {
+        if self.from.is_none() && self.to.is_none() {
+            return Ok(None);
+        }
+
+        let from: Option<ValidatedYear> = self.from.map(ValidatedYear::try_from).transpose()?;
+        let to: Option<ValidatedYear> = self.to.map(ValidatedYear::try_from).transpose()?;
+
+        Ok(Some(FindByRange::new(from, to)))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("2020-03" => Ok((2020, 3)) ; "Should parse year and month")]
+    #[test_case(" 1999-12 " => Ok((1999, 12)) ; "Should parse with whitespaces around")]
+    #[test_case("2020" => Err("2020 is not in the format YYYY-MM".to_owned()) ; "Should fail without month")]
+    #[test_case("2020-aa" => Err("2020-aa is not in the format YYYY-MM".to_owned()) ; "Should fail with month not a number")]
+    fn should_parse_year_month(given: &str) -> Result<(u32, u32), String> {
+        parse_year_month(given)
+    }
+
+    #[test]
+    fn should_create_range_for_all_months_in_year() {
+        let given = DeleteMonthlyRangeArg::new(Some(2020), None, None);
+
+        let actual = given
+            .to_range()
+            .expect("Valid year given")
+            .expect("Range should be created");
+
+        let expected = FindByRange::new(
+            Some(validate_year_month((2020, 1)).unwrap()),
+            Some(validate_year_month((2020, 12)).unwrap()),
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_create_no_range_without_bounds() {
+        let given = DeleteMonthlyRangeArg::default();
+        assert!(given.to_range().expect("No error without bounds").is_none());
+    }
 }
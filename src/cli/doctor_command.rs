@@ -0,0 +1,10 @@
+use clap::Args;
+use derive_new::new;
+
+#[derive(Args, CopyGetters, new, Default)]
+pub struct DoctorArg {
+    #[arg(long, short)]
+    #[getset(get_copy = "pub")]
+    /// If provided then the found problems are only listed without offering fixes
+    report_only: bool,
+}
@@ -1,6 +1,5 @@
 use crate::core::app_options::AppOptions;
 use crate::prelude::*;
-use super::build_env_name;
 use clap::Args;
 use derive_new::new;
 
@@ -16,6 +15,19 @@ pub struct EditCommonArgs {
     /// without any involvement of editor.
     /// Output will be empty if the selected journal was not created so far.
     show_only: bool,
+    #[arg(long, requires = "show_only")]
+    #[getset(get_copy = "pub")]
+    #[new(default)]
+    /// Prints the content for the flag show only as it is.
+    /// Without this flag the content is rendered as markdown and shown via $PAGER
+    /// if stdout is a terminal.
+    raw: bool,
+    #[arg(long)]
+    #[getset(get_copy = "pub", set = "pub")]
+    #[new(default)]
+    /// If the selected journal is only found in an archive folder then it is copied into
+    /// the data folder and opened there. Without this flag archived journals are not opened.
+    restore_archived: bool,
 }
 
 impl EditCommonArgs {
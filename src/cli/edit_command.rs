@@ -3,14 +3,28 @@ use crate::AppResult;
 use date_validation_types::ValidatedDate;
 use chrono::Local;
 use clap::{Parser, Args};
+use super::day_range_argument::DayRangeArg;
 use super::edit_argument::EditCommonArgs;
+use super::navigate_argument::NavigateArg;
 
-#[derive(Parser, Default, Getters)]
-#[getset(get = "pub")]
+#[derive(Parser, Default, Getters, CopyGetters)]
 pub struct EditCommandAndArgs {
     #[command(flatten)]
+    #[getset(get = "pub")]
     command: EditCommand,
     #[command(flatten)]
+    #[getset(get = "pub")]
+    navigate: NavigateArg,
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    range: DayRangeArg,
+    #[arg(long, requires = "DayRangeArg")]
+    #[getset(get_copy = "pub")]
+    /// Only opens the journals of the range which exist already.
+    /// Without this flag missing journals of the range are created from their template first.
+    existing_only: bool,
+    #[command(flatten)]
+    #[getset(get = "pub")]
     option: EditCommonArgs,
 }
 
@@ -1,6 +1,7 @@
 use clap::Parser;
 
 use super::edit_argument::EditCommonArgs;
+use super::navigate_argument::NavigateArg;
 
 #[derive(Parser, Getters, CopyGetters)]
 pub struct EditByYear {
@@ -9,5 +10,8 @@ pub struct EditByYear {
     year: Option<u32>,
     #[getset(get = "pub")]
     #[command(flatten)]
+    navigate: NavigateArg,
+    #[getset(get = "pub")]
+    #[command(flatten)]
     option: EditCommonArgs,
 }
@@ -0,0 +1,22 @@
+use crate::core::date_models::JournalKind;
+use clap::Args;
+
+#[derive(Args, Default, Clone, Copy)]
+pub struct JournalKindArg {
+    #[arg(long, conflicts_with = "yearly")]
+    /// If given then the monthly journal of the selected date is used instead of the daily one
+    monthly: bool,
+    #[arg(long)]
+    /// If given then the yearly journal of the selected date is used instead of the daily one
+    yearly: bool,
+}
+
+impl JournalKindArg {
+    pub fn kind(&self) -> JournalKind {
+        match (self.monthly, self.yearly) {
+            (true, _) => JournalKind::Monthly,
+            (_, true) => JournalKind::Yearly,
+            _ => JournalKind::Daily,
+        }
+    }
+}
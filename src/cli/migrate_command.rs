@@ -0,0 +1,27 @@
+use clap::Args;
+use derive_new::new;
+
+#[derive(Args, Getters, CopyGetters, new, Default)]
+pub struct MigrateArg {
+    #[arg(long)]
+    #[getset(get = "pub")]
+    /// Naming pattern of daily journals before the migration. Example: {yyyy}-{mm}-{dd}.md
+    /// Journals in the default format or the configured pattern are always recognized.
+    from_daily_pattern: Option<String>,
+    #[arg(long)]
+    #[getset(get = "pub")]
+    /// Naming pattern of monthly journals before the migration. Example: {yyyy}-{mm}.md
+    from_monthly_pattern: Option<String>,
+    #[arg(long)]
+    #[getset(get = "pub")]
+    /// Naming pattern of yearly journals before the migration. Example: {yyyy}.md
+    from_yearly_pattern: Option<String>,
+    #[arg(long, short)]
+    #[getset(get_copy = "pub")]
+    /// If provided then no prompt will show up for confirmation before journals are moved
+    skip_confirmation: bool,
+    #[arg(long)]
+    #[getset(get_copy = "pub")]
+    /// If provided then the planned moves are only listed without moving any journal
+    dry_run: bool,
+}
@@ -8,11 +8,24 @@ pub(crate) use build_env_name;
 
 pub mod app_args;
 
+pub mod append_command;
+pub mod cat_command;
+pub mod config_command;
+pub mod day_range_argument;
 pub mod deletion_arguments;
+pub mod doctor_command;
 pub mod edit_argument;
 pub mod edit_command;
 pub mod edit_year;
+pub mod journal_kind_arg;
 pub mod list_command;
+pub mod migrate_command;
 pub mod month_edit_command;
 pub mod month_list_command;
+pub mod navigate_argument;
+pub mod on_this_day_command;
+pub mod path_command;
 pub mod prompt;
+pub mod section_command;
+pub mod trash_command;
+pub mod write_command;
@@ -3,6 +3,7 @@ use date_validation_types::{ValidatedMonth, ValidatedYear};
 use crate::{core::date_models::open_by::OpenByMonthInYear, AppResult};
 
 use super::edit_argument::EditCommonArgs;
+use super::navigate_argument::NavigateArg;
 
 #[derive(Parser, Getters)]
 #[getset(get = "pub")]
@@ -10,6 +11,8 @@ pub struct EditByMonthCommandAndArgs {
     #[command(flatten)]
     command: EditByMonthCommand,
     #[command(flatten)]
+    navigate: NavigateArg,
+    #[command(flatten)]
     option: EditCommonArgs,
 }
 #[derive(Args)]
@@ -0,0 +1,33 @@
+use clap::Args;
+
+use crate::core::date_models::NavigateBy;
+
+#[derive(Args, Default)]
+#[group(multiple = false)]
+pub struct NavigateArg {
+    #[arg(long)]
+    /// If given then the most recent existing journal is selected
+    last: bool,
+    #[arg(long)]
+    /// If given then the nearest existing journal before the selected one is selected
+    prev: bool,
+    #[arg(long)]
+    /// If given then the nearest existing journal after the selected one is selected
+    next: bool,
+    #[arg(long)]
+    /// If given then any existing journal is selected by chance
+    random: bool,
+}
+
+impl NavigateArg {
+    /// Returns none if no existing journal should be selected.
+    pub fn to_navigate_by(&self) -> Option<NavigateBy> {
+        match (self.last, self.prev, self.next, self.random) {
+            (true, _, _, _) => Some(NavigateBy::Last),
+            (_, true, _, _) => Some(NavigateBy::Previous),
+            (_, _, true, _) => Some(NavigateBy::Next),
+            (_, _, _, true) => Some(NavigateBy::Random),
+            _ => None,
+        }
+    }
+}
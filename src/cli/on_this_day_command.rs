@@ -0,0 +1,14 @@
+use clap::Args;
+
+use super::edit_command::EditCommand;
+
+#[derive(Args, Getters, CopyGetters, Default)]
+pub struct OnThisDayArg {
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    date: EditCommand,
+    #[arg(short, long)]
+    #[getset(get_copy = "pub")]
+    /// If given then all daily journals within the same week of previous years are shown
+    week: bool,
+}
@@ -0,0 +1,25 @@
+use clap::Args;
+
+use super::edit_command::EditCommand;
+use super::journal_kind_arg::JournalKindArg;
+
+#[derive(Args, Getters, CopyGetters, Default)]
+pub struct PathArg {
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    selection: JournalSelectionArg,
+    #[arg(short, long)]
+    #[getset(get_copy = "pub")]
+    /// If given then a missing journal is created from its template without opening an editor
+    create: bool,
+}
+
+#[derive(Args, Getters, Default)]
+#[getset(get = "pub")]
+/// Selects a daily journal like edit or the monthly or yearly journal of the selected day.
+pub struct JournalSelectionArg {
+    #[command(flatten)]
+    date: EditCommand,
+    #[command(flatten)]
+    kind: JournalKindArg,
+}
@@ -0,0 +1,27 @@
+use clap::{Args, ValueEnum};
+
+use super::cat_command::JournalRangeArg;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+/// Formats in which the extracted sections are printed.
+pub enum SectionFormat {
+    #[default]
+    Text,
+    Json,
+    Markdown,
+}
+
+#[derive(Args, Getters, CopyGetters)]
+pub struct SectionArg {
+    #[getset(get = "pub")]
+    /// Heading whose content is printed. Either given with its marks like "## Blockers" or only
+    /// by its title like "Blockers" which matches a heading of any level regardless of case.
+    heading: String,
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    range: JournalRangeArg,
+    #[arg(short, long, value_enum, default_value = "text")]
+    #[getset(get_copy = "pub")]
+    /// Format of the output
+    format: SectionFormat,
+}
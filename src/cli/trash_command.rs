@@ -0,0 +1,38 @@
+use clap::{Args, Subcommand};
+use derive_new::new;
+
+use super::deletion_arguments::CommonDeleteArg;
+
+#[derive(Subcommand)]
+pub enum TrashCommand {
+    /// Lists all deleted journals in the trash with the point of their deletion.
+    /// The most recently deleted journals are listed first.
+    #[command(visible_alias = "l")]
+    List,
+    /// Moves a deleted journal out of the trash back into the data folder.
+    #[command(visible_alias = "r")]
+    Restore(RestoreTrashArg),
+    /// Removes deleted journals from the trash for good.
+    #[command(visible_alias = "p")]
+    Purge(PurgeTrashArg),
+}
+
+#[derive(Args, Getters, new)]
+pub struct RestoreTrashArg {
+    #[getset(get = "pub")]
+    /// File name of the deleted journal as shown by the list command of trash.
+    /// Example: 2023_03_09_daily.md
+    journal: String,
+}
+
+#[derive(Args, Getters, CopyGetters, new)]
+pub struct PurgeTrashArg {
+    #[arg(long)]
+    #[getset(get_copy = "pub")]
+    /// Only removes journals which were deleted more than the given number of days ago.
+    /// If not given then all journals in the trash are removed.
+    older_than: Option<u32>,
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    common_arg: CommonDeleteArg,
+}
@@ -0,0 +1,36 @@
+use clap::Args;
+
+use crate::core::write_actions::WriteMode;
+
+use super::edit_command::EditCommand;
+use super::journal_kind_arg::JournalKindArg;
+
+#[derive(Args, Getters, CopyGetters, Default)]
+pub struct WriteArg {
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    date: EditCommand,
+    #[command(flatten)]
+    #[getset(get = "pub")]
+    kind: JournalKindArg,
+    #[arg(long, conflicts_with = "prepend")]
+    /// If given then the content from stdin is added at the end of the journal
+    append: bool,
+    #[arg(long)]
+    /// If given then the content from stdin is added at the start of the journal
+    prepend: bool,
+    #[arg(short, long)]
+    #[getset(get_copy = "pub")]
+    /// If given then a journal with content is replaced without refusal
+    force: bool,
+}
+
+impl WriteArg {
+    pub fn mode(&self) -> WriteMode {
+        match (self.append, self.prepend) {
+            (true, _) => WriteMode::Append,
+            (_, true) => WriteMode::Prepend,
+            _ => WriteMode::Replace,
+        }
+    }
+}
@@ -1,9 +1,16 @@
+pub mod config_check;
+pub mod config_edit;
+pub mod config_init;
+pub mod config_layers;
+pub mod config_show;
+pub mod encryption_config;
 pub mod path_from_config;
+pub use encryption_config::EncryptionConfig;
 pub use path_from_config::PatchFromConfig;
 
 use std::{
     path::{PathBuf, Path},
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     borrow::Cow,
 };
 
@@ -13,8 +20,11 @@ use crate::core::template::{CommandToExecute, OsCommandProcossor};
 use crate::prelude::*;
 
 use super::{
-    constants::CONF_FILE_NAME, template::PlaceholderTemplate, file_access, app_options::AppOptions,
-    dates_names::ResolvePlaceholders,
+    template::PlaceholderTemplate,
+    file_access,
+    app_options::AppOptions,
+    dates_names::{ResolvePlaceholders, JournalExtension, NamingPattern},
+    data_layout,
 };
 
 macro_rules! path_from_conf_getter {
@@ -25,28 +35,103 @@ macro_rules! path_from_conf_getter {
     };
 }
 
-#[derive(Deserialize, Default, Debug, Getters)]
+#[derive(Deserialize, Default, Debug, Getters, CopyGetters)]
 /// Contains access to data which is provided by the conf file of app located at the app conf
 /// folder
 pub struct AppConfig {
     yearly_template: Option<String>,
     monthly_template: Option<String>,
     daily_template: Option<String>,
+    #[getset(get_copy = "pub")]
+    /// Extension of new created yearly journals
+    yearly_extension: Option<JournalExtension>,
+    #[getset(get_copy = "pub")]
+    /// Extension of new created monthly journals
+    monthly_extension: Option<JournalExtension>,
+    #[getset(get_copy = "pub")]
+    /// Extension of new created daily journals
+    daily_extension: Option<JournalExtension>,
     #[getset(get = "pub")]
+    /// Pattern for the file names of yearly journals. Example: {yyyy}.{ext}
+    yearly_name_pattern: Option<NamingPattern>,
+    #[getset(get = "pub")]
+    /// Pattern for the file names of monthly journals. Example: {yyyy}-{mm}.{ext}
+    monthly_name_pattern: Option<NamingPattern>,
+    #[getset(get = "pub")]
+    /// Pattern for the file names of daily journals. Example: {yyyy}-{mm}-{dd}.{ext}
+    daily_name_pattern: Option<NamingPattern>,
+    /// Folder with the journals. Used if no data path is given via CLI or environment variable.
     data_foler: Option<String>,
+    #[getset(get = "pub")]
+    /// Folders within the data folder in which journals are placed. Example: {year}/{month}
+    data_layout: Option<String>,
+    /// Additional folders with journals which are only read but never changed.
+    archive_folders: Option<Vec<String>>,
     placeholders: Option<Vec<PlaceHolder>>,
     #[getset(get = "pub")]
     editor: Option<String>,
+    #[getset(get = "pub")]
+    /// Heading under which text is appended by the append command. Example: ## Log
+    append_section: Option<String>,
+    #[getset(get = "pub")]
+    /// Header printed before every journal by the cat command. Example: === {date} {kind} ===
+    cat_header: Option<String>,
+    #[getset(get = "pub")]
+    /// Heading whose section is taken into the rollups of monthly and yearly journals.
+    /// Example: ## Summary
+    rollup_section: Option<String>,
+    #[getset(get = "pub")]
+    encryption: Option<EncryptionConfig>,
+    #[getset(get = "pub")]
+    /// Notebook which is used if none is selected via CLI or environment variable.
+    default_notebook: Option<String>,
+    #[getset(get = "pub")]
+    /// Named notebooks. Options of the selected notebook take precedence over the other options.
+    notebooks: Option<BTreeMap<String, toml::Table>>,
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    /// Name of the notebook whose options are applied.
+    selected_notebook: Option<String>,
     #[serde(skip)]
     #[getset(get = "pub")]
     /// Path to folder where the config file loaded from
     root_path: PathBuf,
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    /// Config files which were merged into this config. Ordered from the lowest to the highest
+    /// precedence.
+    layer_files: Vec<PathBuf>,
 }
 
 impl AppConfig {
     path_from_conf_getter! {monthly_template}
     path_from_conf_getter! {yearly_template}
     path_from_conf_getter! {daily_template}
+    path_from_conf_getter! {data_foler}
+
+    /// Returns the data folder given by the selected notebook itself if there is one.
+    pub fn notebook_data_folder(&self) -> Option<PathBuf> {
+        let selected = self.selected_notebook.as_ref()?;
+        let folder = self
+            .notebooks
+            .as_ref()?
+            .get(selected)?
+            .get("data_foler")?
+            .as_str()?;
+
+        PatchFromConfig::new(Some(folder.to_owned())).try_to_resolved_path(self)
+    }
+
+    /// Returns the resolved paths of all archive folders.
+    pub fn archive_folders(&self) -> Vec<PathBuf> {
+        self.archive_folders
+            .iter()
+            .flatten()
+            .filter_map(|folder| {
+                PatchFromConfig::new(Some(folder.clone())).try_to_resolved_path(self)
+            })
+            .collect()
+    }
 
     /// Fetches all placeholders from the config.
     pub fn create_template_placeholder_for<'a>(
@@ -101,7 +186,8 @@ impl AppConfig {
         }
     }
 
-    pub fn try_from_file_system(option: &AppOptions) -> AppResult<Option<Self>> {
+    /// Returns the folder in which the config file is expected.
+    pub fn resolve_conf_folder(option: &AppOptions) -> AppResult<PathBuf> {
         let resolved_path = if let Some(path) = option.general().config_path() {
             debug!("Using  conf path provided by cli or env.");
             file_access::resolve_str_as_path(path)
@@ -112,29 +198,125 @@ impl AppConfig {
         };
         debug!("Using {:?} as conf folder for app.", &resolved_path);
 
-        let path_to_conf_file = resolved_path.join(CONF_FILE_NAME);
+        Ok(resolved_path)
+    }
+
+    /// Loads the config files and merges them key by key. Placeholders are combined by their key.
+    /// From the lowest to the highest precedence these are the system-wide config file,
+    /// the config file in the resolved config folder and the nearest project config file
+    /// within the current folder or its parents.
+    /// Options of a project config file which would run programs or touch files outside of
+    /// the project are left out. See [`config_layers::merge_project_layer`]
+    /// Options of the selected notebook or the default notebook are merged on top.
+    /// Returns none if there is no config file at all.
+    /// An invalid config file is only warned about and left out unless the strict mode is on.
+    /// ## Errors
+    /// - A notebook is selected which is not given in any config file.
+    /// - In strict mode if a config file has any problem. See [`config_check::check_content`]
+    /// - In strict mode if a config file or the merged config files can not be loaded.
+    pub fn try_from_file_system(option: &AppOptions) -> AppResult<Option<Self>> {
+        let resolved_path = Self::resolve_conf_folder(option)?;
+        let current_folder = std::env::current_dir().ok();
+        let layer_files =
+            config_layers::find_layer_files(&resolved_path, current_folder.as_deref());
+
+        if layer_files.is_empty() {
+            info!("No config file found for conf folder {:?}", resolved_path);
+        }
 
-        debug!("Using {:?} as conf file for app.", &path_to_conf_file);
-        if path_to_conf_file.exists() {
+        let mut merged = toml::Table::new();
+        let mut loaded_files = Vec::with_capacity(layer_files.len());
+        for path_to_conf_file in layer_files {
+            debug!("Using {:?} as conf file for app.", &path_to_conf_file);
+            let folder = path_to_conf_file
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
             let content = std::fs::read_to_string(&path_to_conf_file)
                 .context("could not read config file by given path")?;
 
-            match toml::from_str::<AppConfig>(&content) {
-                Ok(mut parsed_content) => {
-                    parsed_content.root_path = resolved_path;
-                    Ok(Some(parsed_content))
+            if option.general().strict_config() {
+                let problems = config_check::check_content(&content, &folder);
+                if !problems.is_empty() {
+                    let listed: Vec<String> = problems
+                        .iter()
+                        .map(|problem| format!("- {problem}"))
+                        .collect();
+                    bail!(
+                        "Config file {:?} is invalid:\n{}",
+                        &path_to_conf_file,
+                        listed.join("\n")
+                    );
+                }
+            }
+
+            match config_layers::parse_layer(&content, &folder) {
+                Ok(layer) if config_layers::is_project_file(&path_to_conf_file) => {
+                    config_layers::merge_project_layer(&mut merged, layer, &folder);
+                    loaded_files.push(path_to_conf_file);
+                }
+                Ok(layer) => {
+                    config_layers::merge_layer(&mut merged, layer);
+                    loaded_files.push(path_to_conf_file);
                 }
-                Err(error) => {
-                    warn!("App config file is not in valid format.\n Error: {}", error);
-                    Ok(None)
+                Err(error) if option.general().strict_config() => {
+                    return Err(
+                        error.context(format!("Config file {:?} is invalid", &path_to_conf_file))
+                    )
                 }
+                Err(error) => warn!(
+                    "App config file {:?} is not in valid format and is left out.\n Error: {}",
+                    &path_to_conf_file, error
+                ),
+            }
+        }
+
+        let selected_notebook = option.general().notebook().as_deref();
+        if loaded_files.is_empty() && selected_notebook.is_none() {
+            return Ok(None);
+        }
+        let notebook = config_layers::apply_notebook(&mut merged, selected_notebook)?;
+
+        match toml::Value::Table(merged).try_into::<AppConfig>() {
+            Ok(mut parsed_content) => {
+                parsed_content.root_path = resolved_path;
+                parsed_content.layer_files = loaded_files;
+                parsed_content.selected_notebook = notebook;
+                Ok(Some(parsed_content))
+            }
+            Err(error) if option.general().strict_config() => {
+                Err(AppError::new(error).context("Merged config files are invalid"))
+            }
+            Err(error) => {
+                warn!(
+                    "Merged app config files are not in valid format.\n Error: {}",
+                    error
+                );
+                Ok(None)
             }
-        } else {
-            info!("No config file found at {:?}", path_to_conf_file);
-            Ok(None)
         }
     }
 
+    /// Fails if a naming pattern has not exactly the placeholders of its kind of journal
+    /// or if the data layout leads outside of the data folder or into hidden folders.
+    fn ensure_valid_values(&self) -> AppResult {
+        if let Some(layout) = &self.data_layout {
+            data_layout::ensure_valid_layout(layout)?;
+        }
+
+        for (pattern, with_month, with_day) in [
+            (&self.daily_name_pattern, true, true),
+            (&self.monthly_name_pattern, true, false),
+            (&self.yearly_name_pattern, false, false),
+        ] {
+            if let Some(to_check) = pattern {
+                to_check.ensure_placeholders(with_month, with_day)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns none if there is no template file at the given parameter.
     pub fn try_get_template_file_content(&self, path: &Path) -> AppResult<Option<String>> {
         if path.exists() {
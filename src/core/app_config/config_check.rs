@@ -0,0 +1,407 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use derive_new::new;
+use thiserror::Error;
+use toml::{Table, Value};
+
+use crate::core::app_options::AppOptions;
+use crate::core::constants::CONF_FILE_NAME;
+use crate::core::markdown_sections::heading_level;
+use crate::prelude::*;
+
+use super::{config_layers, AppConfig};
+
+/// Every key which is allowed at the top level of the config file.
+const KNOWN_KEYS: &[&str] = &[
+    "yearly_template",
+    "monthly_template",
+    "daily_template",
+    "yearly_extension",
+    "monthly_extension",
+    "daily_extension",
+    "yearly_name_pattern",
+    "monthly_name_pattern",
+    "daily_name_pattern",
+    "data_foler",
+    "data_layout",
+    "archive_folders",
+    "placeholders",
+    "editor",
+    "append_section",
+    "cat_header",
+    "rollup_section",
+    "encryption",
+    "default_notebook",
+    "notebooks",
+];
+/// Keys of the top level which are not allowed within a notebook.
+const NOT_IN_NOTEBOOK_KEYS: &[&str] = &["default_notebook", "notebooks"];
+/// Every key which is allowed within one placeholder of the config file.
+const KNOWN_PLACEHOLDER_KEYS: &[&str] = &["key", "value", "is_command"];
+/// Every key which is allowed within the encryption section of the config file.
+const KNOWN_ENCRYPTION_KEYS: &[&str] = &["key_file", "passphrase_env"];
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ConfigProblem {
+    #[error("Line {line}, column {column}: {message}")]
+    Syntax {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("Unknown key {0}")]
+    UnknownKey(String),
+    #[error("Template {path:?} given by {key} does not exist")]
+    MissingTemplate { key: String, path: PathBuf },
+    #[error("Placeholder key {0} is given more than once")]
+    DuplicatePlaceholder(String),
+    #[error("{0}")]
+    Invalid(String),
+}
+
+#[derive(Error, Debug, PartialEq, Eq, Getters, new)]
+#[getset(get = "pub")]
+#[error("{path:?}: {problem}")]
+/// Problem found within one of the config files.
+pub struct FileProblem {
+    path: PathBuf,
+    problem: ConfigProblem,
+}
+
+#[derive(Debug)]
+pub enum ConfigCheckResult {
+    /// There is no config file at all. The path is the one of the config file in the config folder.
+    NoConfigFile(PathBuf),
+    /// Config files at the given paths were checked. No problems means the config files are valid.
+    Checked {
+        paths: Vec<PathBuf>,
+        problems: Vec<FileProblem>,
+    },
+}
+
+/// Checks every config file which is merged for problems. These are the system-wide file,
+/// the file in the resolved config folder and the nearest project file.
+/// See [`config_layers::find_layer_files`]
+pub fn check_config_file(option: &AppOptions) -> AppResult<ConfigCheckResult> {
+    let conf_folder = AppConfig::resolve_conf_folder(option)?;
+    let current_folder = std::env::current_dir().ok();
+    let paths = config_layers::find_layer_files(&conf_folder, current_folder.as_deref());
+
+    if paths.is_empty() {
+        return Ok(ConfigCheckResult::NoConfigFile(
+            conf_folder.join(CONF_FILE_NAME),
+        ));
+    }
+
+    let problems = check_files(&paths)?;
+    Ok(ConfigCheckResult::Checked { paths, problems })
+}
+
+/// Returns all problems of the given config files together with the file of every problem.
+/// Paths of templates are resolved relative to the folder of their file.
+/// ## Errors
+/// - A config file could not be read.
+pub fn check_files(paths: &[PathBuf]) -> AppResult<Vec<FileProblem>> {
+    let mut problems = Vec::new();
+    for path in paths {
+        let folder = path.parent().unwrap_or(Path::new(""));
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read config file {:?}", path))?;
+        problems.extend(
+            check_content(&content, folder)
+                .into_iter()
+                .map(|problem| FileProblem::new(path.clone(), problem)),
+        );
+    }
+
+    Ok(problems)
+}
+
+/// Returns all problems of the given content of a config file.
+/// Paths of templates are resolved relative to the given config folder if needed.
+pub fn check_content(content: &str, conf_folder: &Path) -> Vec<ConfigProblem> {
+    let table: Table = match toml::from_str(content) {
+        Ok(table) => table,
+        Err(error) => return vec![to_syntax_problem(content, &error)],
+    };
+
+    let mut problems = find_unknown_keys(&table);
+
+    let mut config: AppConfig = match toml::from_str(content) {
+        Ok(config) => config,
+        Err(error) => {
+            problems.push(ConfigProblem::Invalid(error.message().to_owned()));
+            return problems;
+        }
+    };
+    config.root_path = conf_folder.to_path_buf();
+
+    if let Err(error) = config.ensure_valid_values() {
+        problems.push(ConfigProblem::Invalid(error.to_string()));
+    }
+
+    for (key, template) in [
+        ("daily_template", config.daily_template()),
+        ("monthly_template", config.monthly_template()),
+        ("yearly_template", config.yearly_template()),
+    ] {
+        if let Some(path) = template.try_to_resolved_path(&config) {
+            if !path.exists() {
+                problems.push(ConfigProblem::MissingTemplate {
+                    key: key.to_owned(),
+                    path,
+                });
+            }
+        }
+    }
+
+    for (name, notebook) in config.notebooks().iter().flatten() {
+        match Value::Table(notebook.clone()).try_into::<AppConfig>() {
+            Ok(notebook) => {
+                if let Err(error) = notebook.ensure_valid_values() {
+                    problems.push(ConfigProblem::Invalid(format!("Notebook {name}: {error}")));
+                }
+            }
+            Err(error) => problems.push(ConfigProblem::Invalid(format!(
+                "Notebook {name}: {}",
+                error.message()
+            ))),
+        }
+    }
+
+    if let Some(section) = config.append_section() {
+        if heading_level(section.trim()).is_none() {
+            problems.push(ConfigProblem::Invalid(format!(
+                "Option append_section {section} is not a markdown heading like \"## Log\""
+            )));
+        }
+    }
+
+    if let Some(default_notebook) = config.default_notebook() {
+        let is_given = config
+            .notebooks()
+            .as_ref()
+            .map_or(false, |notebooks| notebooks.contains_key(default_notebook));
+        if !is_given {
+            problems.push(ConfigProblem::Invalid(format!(
+                "Default notebook {default_notebook} is not given in notebooks"
+            )));
+        }
+    }
+
+    if let Some(placeholders) = config.placeholders.as_ref() {
+        let mut seen = HashSet::new();
+        let mut reported = HashSet::new();
+        for placeholder in placeholders {
+            let key = placeholder.key();
+            if !seen.insert(key) && reported.insert(key) {
+                problems.push(ConfigProblem::DuplicatePlaceholder(key.clone()));
+            }
+        }
+    }
+
+    problems
+}
+
+fn to_syntax_problem(content: &str, error: &toml::de::Error) -> ConfigProblem {
+    let offset = error.span().map(|span| span.start).unwrap_or_default();
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map(|in_line| in_line.chars().count())
+        .unwrap_or_default()
+        + 1;
+
+    ConfigProblem::Syntax {
+        message: error.message().to_owned(),
+        line,
+        column,
+    }
+}
+
+fn find_unknown_keys(table: &Table) -> Vec<ConfigProblem> {
+    let mut problems = unknown_in(table, KNOWN_KEYS, "");
+    problems.extend(unknown_nested_keys(table, ""));
+
+    if let Some(Value::Table(notebooks)) = table.get("notebooks") {
+        let known_in_notebook: Vec<&str> = KNOWN_KEYS
+            .iter()
+            .copied()
+            .filter(|key| !NOT_IN_NOTEBOOK_KEYS.contains(key))
+            .collect();
+        for (name, notebook) in notebooks {
+            if let Value::Table(notebook) = notebook {
+                let prefix = format!("notebooks.{name}.");
+                problems.extend(unknown_in(notebook, &known_in_notebook, &prefix));
+                problems.extend(unknown_nested_keys(notebook, &prefix));
+            }
+        }
+    }
+
+    problems
+}
+
+/// Returns unknown keys of the given table within placeholders and the encryption section.
+fn unknown_nested_keys(table: &Table, prefix: &str) -> Vec<ConfigProblem> {
+    let mut problems = Vec::new();
+
+    if let Some(Value::Array(placeholders)) = table.get("placeholders") {
+        for (index, placeholder) in placeholders.iter().enumerate() {
+            if let Value::Table(placeholder) = placeholder {
+                let prefix = format!("{prefix}placeholders[{index}].");
+                problems.extend(unknown_in(placeholder, KNOWN_PLACEHOLDER_KEYS, &prefix));
+            }
+        }
+    }
+
+    if let Some(Value::Table(encryption)) = table.get("encryption") {
+        let prefix = format!("{prefix}encryption.");
+        problems.extend(unknown_in(encryption, KNOWN_ENCRYPTION_KEYS, &prefix));
+    }
+
+    problems
+}
+
+fn unknown_in(table: &Table, known: &[&str], prefix: &str) -> Vec<ConfigProblem> {
+    table
+        .keys()
+        .filter(|key| !known.contains(&key.as_str()))
+        .map(|key| ConfigProblem::UnknownKey(format!("{prefix}{key}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_report_syntax_error_with_line_and_column() {
+        let given = "editor = \"vim\"\ndaily_template = \n";
+        let actual = check_content(given, Path::new("/does/not/matter"));
+
+        assert!(matches!(
+            actual.as_slice(),
+            [ConfigProblem::Syntax { line: 2, .. }]
+        ));
+    }
+
+    #[test]
+    fn should_report_unknown_keys() {
+        let given = r#"
+editr = "vim"
+[[placeholders]]
+key = "a"
+value = "b"
+is_comand = true
+[encryption]
+key = "secret"
+"#;
+        let actual = check_content(given, Path::new("/does/not/matter"));
+
+        assert_eq!(
+            vec![
+                ConfigProblem::UnknownKey("editr".to_owned()),
+                ConfigProblem::UnknownKey("placeholders[0].is_comand".to_owned()),
+                ConfigProblem::UnknownKey("encryption.key".to_owned()),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn should_report_duplicate_placeholders_and_missing_templates() {
+        let given = r#"
+daily_template = "+daily.template"
+[[placeholders]]
+key = "a"
+value = "b"
+[[placeholders]]
+key = "a"
+value = "c"
+"#;
+        let actual = check_content(given, Path::new("/does/not/exist"));
+
+        assert_eq!(
+            vec![
+                ConfigProblem::MissingTemplate {
+                    key: "daily_template".to_owned(),
+                    path: PathBuf::from("/does/not/exist/daily.template")
+                },
+                ConfigProblem::DuplicatePlaceholder("a".to_owned()),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn should_report_problems_within_notebooks() {
+        let given = r#"
+default_notebook = "private"
+[notebooks.work]
+editr = "nano"
+default_notebook = "work"
+[notebooks.work.encryption]
+key = "secret"
+"#;
+        let actual = check_content(given, Path::new("/does/not/matter"));
+
+        assert_eq!(
+            vec![
+                ConfigProblem::UnknownKey("notebooks.work.default_notebook".to_owned()),
+                ConfigProblem::UnknownKey("notebooks.work.editr".to_owned()),
+                ConfigProblem::UnknownKey("notebooks.work.encryption.key".to_owned()),
+                ConfigProblem::Invalid(
+                    "Default notebook private is not given in notebooks".to_owned()
+                ),
+            ],
+            actual
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_values_within_notebooks() {
+        let given = r#"
+[notebooks.work]
+daily_extension = "docx"
+[notebooks.personal]
+daily_name_pattern = "{yyyy}-{mm}.md"
+"#;
+        let actual = check_content(given, Path::new("/does/not/matter"));
+
+        assert_eq!(2, actual.len());
+        assert!(actual
+            .iter()
+            .all(|problem| matches!(problem, ConfigProblem::Invalid(_))));
+        assert!(actual[0].to_string().starts_with("Notebook personal: "));
+        assert!(actual[1].to_string().starts_with("Notebook work: "));
+    }
+
+    #[test]
+    fn should_report_append_section_which_is_no_heading() {
+        let actual = check_content("append_section = \"Log\"", Path::new("/does/not/matter"));
+
+        assert!(matches!(actual.as_slice(), [ConfigProblem::Invalid(_)]));
+    }
+
+    #[test]
+    fn should_report_data_layout_leading_out_of_data_folder() {
+        let actual = check_content(
+            "data_layout = \"../../{year}\"",
+            Path::new("/does/not/matter"),
+        );
+
+        assert!(matches!(actual.as_slice(), [ConfigProblem::Invalid(_)]));
+    }
+
+    #[test]
+    fn should_report_nothing_for_valid_content() {
+        let given = r#"
+editor = "vim"
+daily_extension = "org"
+"#;
+        assert!(check_content(given, Path::new("/does/not/matter")).is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml_edit::{value, ArrayOfTables, Document, Item, Table};
+
+use crate::core::app_options::AppOptions;
+use crate::core::constants::CONF_FILE_NAME;
+use crate::prelude::*;
+
+use super::config_check::{self, ConfigProblem};
+use super::AppConfig;
+
+/// Separates the names of a table and an option within it. Example: encryption.key_file
+const KEY_SEPARATOR: char = '.';
+const PLACEHOLDERS_KEY: &str = "placeholders";
+
+/// Returns the value of the given option in the config file in the resolved config folder.
+/// Strings are returned without quotes. Returns none if the option is not given.
+/// ## Errors
+/// - The config file could not be read or is not valid toml.
+pub fn get_value(option: &AppOptions, key: &str) -> AppResult<Option<String>> {
+    let (_, document) = load_document(option)?;
+
+    let mut found = document.as_item();
+    for part in key.split(KEY_SEPARATOR) {
+        found = match found.get(part) {
+            Some(item) => item,
+            None => return Ok(None),
+        };
+    }
+
+    let as_text = match found.as_str() {
+        Some(text) => text.to_owned(),
+        None => found.to_string().trim().to_owned(),
+    };
+
+    Ok(Some(as_text))
+}
+
+/// Sets the given option in the config file to the given string value.
+/// Comments and layout of the config file are kept. The config file is created if needed.
+/// Returns the path to the changed config file.
+/// ## Errors
+/// - The config file is not valid toml.
+/// - The config file would be invalid after the change. Nothing is written in this case.
+pub fn set_value(option: &AppOptions, key: &str, new_value: &str) -> AppResult<PathBuf> {
+    edit_document(option, |document| {
+        let mut parts: Vec<&str> = key.split(KEY_SEPARATOR).collect();
+        let last = parts
+            .pop()
+            .filter(|last| !last.is_empty())
+            .ok_or_else(|| anyhow!("No name of an option is given"))?;
+
+        let mut table = document.as_table_mut();
+        for part in parts {
+            let item = table
+                .entry(part)
+                .or_insert_with(|| Item::Table(Table::new()));
+            table = item
+                .as_table_mut()
+                .ok_or_else(|| anyhow!("Option {} is not a table", part))?;
+        }
+        table[last] = value(new_value);
+
+        Ok(())
+    })
+}
+
+/// Adds a placeholder to the config file. An existing placeholder with the same key gets the
+/// new value instead.
+/// ## Errors
+/// - See [`set_value`]
+pub fn add_placeholder(
+    option: &AppOptions,
+    key: &str,
+    new_value: &str,
+    is_command: bool,
+) -> AppResult<PathBuf> {
+    edit_document(option, |document| {
+        let placeholders = document
+            .entry(PLACEHOLDERS_KEY)
+            .or_insert_with(|| Item::ArrayOfTables(ArrayOfTables::new()))
+            .as_array_of_tables_mut()
+            .ok_or_else(|| anyhow!("Option {} is not a list of tables", PLACEHOLDERS_KEY))?;
+
+        let placeholder = match position_of_placeholder(placeholders, key) {
+            Some(index) => placeholders
+                .get_mut(index)
+                .expect("Index was found within the placeholders"),
+            None => {
+                let mut new_placeholder = Table::new();
+                new_placeholder["key"] = value(key);
+                placeholders.push(new_placeholder);
+                placeholders
+                    .iter_mut()
+                    .last()
+                    .expect("Placeholder was just pushed")
+            }
+        };
+
+        placeholder["value"] = value(new_value);
+        if is_command {
+            placeholder["is_command"] = value(true);
+        } else {
+            placeholder.remove("is_command");
+        }
+
+        Ok(())
+    })
+}
+
+/// Removes the placeholder with the given key from the config file.
+/// ## Errors
+/// - There is no placeholder with the given key.
+/// - See [`set_value`]
+pub fn remove_placeholder(option: &AppOptions, key: &str) -> AppResult<PathBuf> {
+    edit_document(option, |document| {
+        let placeholders = document
+            .get_mut(PLACEHOLDERS_KEY)
+            .and_then(Item::as_array_of_tables_mut);
+        let placeholders = match placeholders {
+            Some(placeholders) => placeholders,
+            None => bail!("There are no placeholders in the config file"),
+        };
+
+        let index = position_of_placeholder(placeholders, key)
+            .ok_or_else(|| anyhow!("There is no placeholder with the key {}", key))?;
+        placeholders.remove(index);
+
+        if placeholders.is_empty() {
+            document.remove(PLACEHOLDERS_KEY);
+        }
+
+        Ok(())
+    })
+}
+
+fn position_of_placeholder(placeholders: &ArrayOfTables, key: &str) -> Option<usize> {
+    placeholders
+        .iter()
+        .position(|placeholder| placeholder.get("key").and_then(Item::as_str) == Some(key))
+}
+
+fn load_document(option: &AppOptions) -> AppResult<(PathBuf, Document)> {
+    let conf_folder = AppConfig::resolve_conf_folder(option)?;
+    let path = conf_folder.join(CONF_FILE_NAME);
+
+    let content = if path.try_exists()? {
+        fs::read_to_string(&path).context("could not read config file by given path")?
+    } else {
+        String::new()
+    };
+    let document = content
+        .parse::<Document>()
+        .with_context(|| format!("Config file {:?} is not valid toml", &path))?;
+
+    Ok((path, document))
+}
+
+/// Applies the given change to the config file and writes it back if it is still valid.
+fn edit_document(
+    option: &AppOptions,
+    change: impl FnOnce(&mut Document) -> AppResult,
+) -> AppResult<PathBuf> {
+    let (path, mut document) = load_document(option)?;
+    change(&mut document)?;
+
+    let new_content = document.to_string();
+    ensure_valid(&new_content, path.parent().unwrap_or(Path::new("")))?;
+
+    if let Some(folder) = path.parent() {
+        fs::create_dir_all(folder)
+            .with_context(|| format!("Could not create config folder at {:?}", folder))?;
+    }
+    fs::write(&path, new_content)
+        .with_context(|| format!("Could not write config file at {:?}", &path))?;
+
+    Ok(path)
+}
+
+/// Fails if the content does not follow the schema of the config file.
+/// Missing template files are only warned about, because they may be created later.
+fn ensure_valid(content: &str, conf_folder: &Path) -> AppResult {
+    let mut listed = Vec::new();
+    for problem in config_check::check_content(content, conf_folder) {
+        match problem {
+            ConfigProblem::MissingTemplate { .. } => warn!("{}", problem),
+            _ => listed.push(format!("- {problem}")),
+        }
+    }
+
+    if !listed.is_empty() {
+        bail!(
+            "Config file is not changed because it would be invalid:\n{}",
+            listed.join("\n")
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::core::app_options::AppOptions;
+use crate::core::constants::CONF_FILE_NAME;
+use crate::prelude::*;
+
+use super::AppConfig;
+
+/// Example config file of the app with every option commented out.
+const EXAMPLE_CONFIG: &str = include_str!("../../../doc/config.toml");
+/// Options of the example config file which are turned on in the written config file.
+/// These point to the written starter templates.
+const ACTIVATED_OPTIONS: &[&str] = &[
+    "yearly_template=\"+yearly.template\"",
+    "monthly_template=\"+monthly.template\"",
+    "daily_template=\"+daily.template\"",
+];
+/// File names of the starter templates with their content.
+const STARTER_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "daily.template",
+        "# Daily journal\n\n## Tasks\n\n## Notes\n",
+    ),
+    (
+        "monthly.template",
+        "# Monthly journal\n\n## Goals\n\n## Review\n",
+    ),
+    (
+        "yearly.template",
+        "# Yearly journal\n\n## Goals\n\n## Review\n",
+    ),
+];
+
+#[derive(Debug, Default, Getters)]
+#[getset(get = "pub")]
+/// Files which were written or left alone while creating the config folder.
+pub struct ConfigInitResult {
+    written: Vec<PathBuf>,
+    /// Files which exist already and were not overwritten.
+    skipped: Vec<PathBuf>,
+}
+
+/// Writes a commented config file and starter templates into the resolved config folder.
+/// Existing files are only overwritten if `force` is true.
+/// ## Errors
+/// - The config folder or a file could not be created.
+pub fn init_config_folder(option: &AppOptions, force: bool) -> AppResult<ConfigInitResult> {
+    let conf_folder = AppConfig::resolve_conf_folder(option)?;
+    fs::create_dir_all(&conf_folder)
+        .with_context(|| format!("Could not create config folder at {:?}", &conf_folder))?;
+
+    let mut result = ConfigInitResult::default();
+    let config_content = create_config_content();
+    let to_write = std::iter::once((CONF_FILE_NAME, config_content.as_str()))
+        .chain(STARTER_TEMPLATES.iter().copied());
+
+    for (file_name, content) in to_write {
+        let path = conf_folder.join(file_name);
+        if !force && path.try_exists()? {
+            result.skipped.push(path);
+            continue;
+        }
+
+        fs::write(&path, content).with_context(|| format!("Could not write file {:?}", &path))?;
+        result.written.push(path);
+    }
+
+    Ok(result)
+}
+
+/// Returns the example config file with the templates turned on.
+fn create_config_content() -> String {
+    ACTIVATED_OPTIONS
+        .iter()
+        .fold(EXAMPLE_CONFIG.to_owned(), |content, option| {
+            content.replace(&format!("# {option}"), option)
+        })
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn should_create_valid_config_with_templates_turned_on() {
+        let actual = create_config_content();
+        let config: AppConfig = toml::from_str(&actual).expect("Created config should be valid");
+
+        assert_eq!(Some("+daily.template".to_owned()), config.daily_template);
+        assert_eq!(
+            Some("+monthly.template".to_owned()),
+            config.monthly_template
+        );
+        assert_eq!(Some("+yearly.template".to_owned()), config.yearly_template);
+        assert!(config.editor().is_none());
+    }
+}
@@ -0,0 +1,482 @@
+use std::path::{Component, Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::core::constants::{
+    CONF_FILE_NAME, PROJECT_CONF_FILE_NAME, SIGN_FOR_FROM_CONF_FOLDER, SYSTEM_CONF_FOLDER,
+};
+use crate::prelude::*;
+
+use super::AppConfig;
+
+/// Keys whose values are paths which may start with a "+".
+const PATH_KEYS: &[&str] = &[
+    "yearly_template",
+    "monthly_template",
+    "daily_template",
+    "data_foler",
+];
+/// Keys whose values are lists of paths which may start with a "+".
+const PATH_LIST_KEYS: &[&str] = &["archive_folders"];
+/// Keys within the encryption section whose values are paths which may start with a "+".
+const ENCRYPTION_PATH_KEYS: &[&str] = &["key_file"];
+/// Keys which a project file must not set. A project file comes with the folder one works in,
+/// for example a cloned repository, so it must not choose the programs to run or the secrets.
+const UNTRUSTED_PROJECT_KEYS: &[&str] = &["editor", "encryption", "archive_folders"];
+/// Keys of a project file whose values are only accepted within the folder of the project file.
+/// Templates outside of it could copy any file of the user into a journal of the project.
+const PROJECT_FOLDER_KEYS: &[&str] = &[
+    "data_foler",
+    "yearly_template",
+    "monthly_template",
+    "daily_template",
+];
+const NOTEBOOKS_KEY: &str = "notebooks";
+const DEFAULT_NOTEBOOK_KEY: &str = "default_notebook";
+
+/// Returns all existing config files from the lowest to the highest precedence:
+/// system-wide file, file in the given config folder and the project file
+/// nearest to the given start folder.
+pub fn find_layer_files(conf_folder: &Path, start_folder: Option<&Path>) -> Vec<PathBuf> {
+    let system = Path::new(SYSTEM_CONF_FOLDER).join(CONF_FILE_NAME);
+    let user = conf_folder.join(CONF_FILE_NAME);
+    let project = start_folder.and_then(|start| {
+        start
+            .ancestors()
+            .map(|folder| folder.join(PROJECT_CONF_FILE_NAME))
+            .find(|path| path.is_file())
+    });
+
+    [Some(system), Some(user), project]
+        .into_iter()
+        .flatten()
+        .filter(|path| path.is_file())
+        .fold(Vec::new(), |mut files, path| {
+            if !files.contains(&path) {
+                files.push(path);
+            }
+            files
+        })
+}
+
+/// Parses the content of one config file as one layer.
+/// Paths starting with a "+" are resolved relative to the given folder of the file.
+/// This way they stay relative to their own file after merging.
+/// ## Errors
+/// - Content is not a valid config file.
+pub fn parse_layer(content: &str, folder: &Path) -> AppResult<Table> {
+    let mut table: Table = toml::from_str(content)?;
+    let config: AppConfig = Value::Table(table.clone()).try_into()?;
+    config.ensure_valid_values()?;
+
+    resolve_all_plus_paths(&mut table, folder);
+    if let Some(Value::Table(notebooks)) = table.get_mut(NOTEBOOKS_KEY) {
+        for (name, notebook) in notebooks.iter_mut() {
+            let notebook = match notebook {
+                Value::Table(notebook) => notebook,
+                _ => bail!("Notebook {} is not a table", name),
+            };
+            let config: AppConfig = Value::Table(notebook.clone())
+                .try_into()
+                .with_context(|| format!("Notebook {} is invalid", name))?;
+            config.ensure_valid_values()?;
+            resolve_all_plus_paths(notebook, folder);
+        }
+    }
+
+    Ok(table)
+}
+
+/// Merges the options of the selected notebook over the given config.
+/// If no notebook is selected then the default notebook is used if there is one.
+/// Returns the name of the applied notebook.
+/// ## Errors
+/// - The selected or default notebook is not given in the config.
+pub fn apply_notebook(merged: &mut Table, selected: Option<&str>) -> AppResult<Option<String>> {
+    let name = match selected.or_else(|| merged.get(DEFAULT_NOTEBOOK_KEY).and_then(Value::as_str)) {
+        Some(name) => name.to_owned(),
+        None => return Ok(None),
+    };
+
+    let notebook = merged
+        .get(NOTEBOOKS_KEY)
+        .and_then(|notebooks| notebooks.get(&name))
+        .and_then(Value::as_table)
+        .cloned()
+        .ok_or_else(|| anyhow!("There is no notebook named {} in the config file", name))?;
+    merge_layer(merged, notebook);
+
+    Ok(Some(name))
+}
+
+/// Returns true if the given config file is a project file.
+pub fn is_project_file(path: &Path) -> bool {
+    path.file_name()
+        .map_or(false, |name| name == PROJECT_CONF_FILE_NAME)
+}
+
+/// Merges the given layer of a project file into the given base key by key like [`merge_layer`].
+/// Options which would run programs or touch files outside of the project are left out with a
+/// warning: the editor, the encryption, archive folders, placeholders with commands and a data
+/// folder or templates outside of the given folder of the project file.
+/// This applies to notebooks as well.
+/// A data layout leading out of the data folder is already rejected by [`parse_layer`].
+pub fn merge_project_layer(base: &mut Table, mut layer: Table, folder: &Path) {
+    restrict_project_table(&mut layer, folder);
+    if let Some(Value::Table(notebooks)) = layer.get_mut(NOTEBOOKS_KEY) {
+        for (_, notebook) in notebooks.iter_mut() {
+            if let Value::Table(notebook) = notebook {
+                restrict_project_table(notebook, folder);
+            }
+        }
+    }
+
+    merge_layer(base, layer);
+}
+
+fn restrict_project_table(table: &mut Table, folder: &Path) {
+    for key in UNTRUSTED_PROJECT_KEYS {
+        if table.remove(*key).is_some() {
+            warn!(
+                "Option {} is not allowed in a project config file and is left out.",
+                key
+            );
+        }
+    }
+
+    for key in PROJECT_FOLDER_KEYS {
+        let is_outside = table
+            .get(*key)
+            .and_then(Value::as_str)
+            .map_or(false, |path| !is_within_folder(Path::new(path), folder));
+        if is_outside {
+            warn!(
+                "Option {} of a project config file must be a path within {:?} and is left out.",
+                key, folder
+            );
+            table.remove(*key);
+        }
+    }
+
+    if let Some(Value::Array(placeholders)) = table.get_mut("placeholders") {
+        placeholders.retain(|placeholder| {
+            let is_command = placeholder
+                .get("is_command")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            if is_command {
+                warn!(
+                    "Placeholder {} with a command is not allowed in a project config file and is left out.",
+                    placeholder.get("key").and_then(Value::as_str).unwrap_or_default()
+                );
+            }
+            !is_command
+        });
+    }
+}
+
+fn is_within_folder(path: &Path, folder: &Path) -> bool {
+    path.is_absolute()
+        && path.starts_with(folder)
+        && !path
+            .components()
+            .any(|component| component == Component::ParentDir)
+}
+
+/// Merges the given layer into the given base key by key.
+/// Values of the layer take precedence. Tables are merged recursively and
+/// placeholders are combined by their key.
+pub fn merge_layer(base: &mut Table, layer: Table) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(base_table)), Value::Table(layer_table)) => {
+                merge_layer(base_table, layer_table)
+            }
+            (Some(Value::Array(base_placeholders)), Value::Array(layer_placeholders))
+                if key == "placeholders" =>
+            {
+                merge_placeholders(base_placeholders, layer_placeholders)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn merge_placeholders(base: &mut Vec<Value>, layer: Vec<Value>) {
+    for placeholder in layer {
+        let same_key = base
+            .iter_mut()
+            .find(|existing| existing.get("key") == placeholder.get("key"));
+        match same_key {
+            Some(existing) => *existing = placeholder,
+            None => base.push(placeholder),
+        }
+    }
+}
+
+fn resolve_all_plus_paths(table: &mut Table, folder: &Path) {
+    resolve_plus_paths(table, PATH_KEYS, folder);
+    resolve_plus_path_lists(table, PATH_LIST_KEYS, folder);
+    if let Some(Value::Table(encryption)) = table.get_mut("encryption") {
+        resolve_plus_paths(encryption, ENCRYPTION_PATH_KEYS, folder);
+    }
+}
+
+fn resolve_plus_paths(table: &mut Table, keys: &[&str], folder: &Path) {
+    for key in keys {
+        if let Some(Value::String(path)) = table.get_mut(*key) {
+            resolve_plus_path(path, folder);
+        }
+    }
+}
+
+fn resolve_plus_path_lists(table: &mut Table, keys: &[&str], folder: &Path) {
+    for key in keys {
+        if let Some(Value::Array(paths)) = table.get_mut(*key) {
+            for path in paths {
+                if let Value::String(path) = path {
+                    resolve_plus_path(path, folder);
+                }
+            }
+        }
+    }
+}
+
+fn resolve_plus_path(path: &mut String, folder: &Path) {
+    if let Some(relative) = path.strip_prefix(SIGN_FOR_FROM_CONF_FOLDER) {
+        *path = folder.join(relative).to_string_lossy().to_string();
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn should_merge_layers_key_by_key() {
+        let mut base = parse_layer(
+            r#"
+editor = "vim"
+daily_extension = "org"
+[encryption]
+passphrase_env = "BASE"
+[[placeholders]]
+key = "a"
+value = "from base"
+[[placeholders]]
+key = "b"
+value = "only base"
+"#,
+            Path::new("/base"),
+        )
+        .unwrap();
+        let layer = parse_layer(
+            r#"
+editor = "nano"
+daily_template = "+daily.template"
+[encryption]
+key_file = "+journal.key"
+[[placeholders]]
+key = "a"
+value = "from layer"
+[[placeholders]]
+key = "c"
+value = "only layer"
+"#,
+            Path::new("/project"),
+        )
+        .unwrap();
+
+        merge_layer(&mut base, layer);
+        let actual: AppConfig = Value::Table(base).try_into().unwrap();
+
+        assert_eq!(&Some("nano".to_owned()), actual.editor());
+        assert_eq!(
+            Some(crate::core::dates_names::JournalExtension::Org),
+            actual.daily_extension()
+        );
+        assert_eq!(
+            Some("/project/daily.template".to_owned()),
+            actual.daily_template
+        );
+        let encryption = actual.encryption().as_ref().unwrap();
+        assert_eq!("BASE", encryption.resolve_passphrase_env());
+        let placeholders: Vec<(&str, &str)> = actual
+            .placeholders
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|placeholder| (placeholder.key().as_str(), placeholder.value().as_str()))
+            .collect();
+        assert_eq!(
+            vec![("a", "from layer"), ("b", "only base"), ("c", "only layer")],
+            placeholders
+        );
+    }
+
+    #[test]
+    fn should_apply_selected_or_default_notebook() {
+        let given = r#"
+editor = "vim"
+default_notebook = "personal"
+[notebooks.work]
+editor = "nano"
+data_foler = "+work"
+[notebooks.personal]
+data_foler = "~/personal"
+"#;
+        let mut selected = parse_layer(given, Path::new("/conf")).unwrap();
+        let mut by_default = selected.clone();
+
+        let name = apply_notebook(&mut selected, Some("work")).unwrap();
+        let config: AppConfig = Value::Table(selected).try_into().unwrap();
+        assert_eq!(Some("work".to_owned()), name);
+        assert_eq!(&Some("nano".to_owned()), config.editor());
+        assert_eq!(Some("/conf/work".to_owned()), config.data_foler);
+
+        let name = apply_notebook(&mut by_default, None).unwrap();
+        let config: AppConfig = Value::Table(by_default).try_into().unwrap();
+        assert_eq!(Some("personal".to_owned()), name);
+        assert_eq!(&Some("vim".to_owned()), config.editor());
+        assert_eq!(Some("~/personal".to_owned()), config.data_foler);
+    }
+
+    #[test]
+    fn should_fail_for_unknown_notebook() {
+        let mut given = parse_layer("editor = \"vim\"", Path::new("/conf")).unwrap();
+        assert!(apply_notebook(&mut given, Some("work")).is_err());
+    }
+
+    #[test]
+    fn should_leave_out_untrusted_options_of_project_layer() {
+        let mut base = parse_layer(
+            r#"
+editor = "vim"
+data_foler = "~/journals"
+[[placeholders]]
+key = "weather"
+value = "curl wttr.in"
+is_command = true
+"#,
+            Path::new("/user"),
+        )
+        .unwrap();
+        let project = parse_layer(
+            r#"
+editor = "sh -c evil"
+archive_folders = ["/"]
+daily_template = "+daily.template"
+[encryption]
+key_file = "/tmp/key"
+[[placeholders]]
+key = "weather"
+value = "rm -rf ~"
+is_command = true
+[[placeholders]]
+key = "team"
+value = "core"
+[notebooks.work]
+editor = "sh -c evil"
+data_foler = "/elsewhere"
+"#,
+            Path::new("/project"),
+        )
+        .unwrap();
+
+        merge_project_layer(&mut base, project, Path::new("/project"));
+        let actual: AppConfig = Value::Table(base.clone()).try_into().unwrap();
+
+        assert_eq!(&Some("vim".to_owned()), actual.editor());
+        assert!(actual.encryption().is_none());
+        assert!(actual.archive_folders.is_none());
+        assert_eq!(Some("~/journals".to_owned()), actual.data_foler);
+        assert_eq!(
+            Some("/project/daily.template".to_owned()),
+            actual.daily_template
+        );
+        let placeholders: Vec<(&str, &str)> = actual
+            .placeholders
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|placeholder| (placeholder.key().as_str(), placeholder.value().as_str()))
+            .collect();
+        assert_eq!(
+            vec![("weather", "curl wttr.in"), ("team", "core")],
+            placeholders
+        );
+        let work = base["notebooks"]["work"].as_table().unwrap();
+        assert!(work.is_empty());
+    }
+
+    #[test]
+    fn should_only_accept_templates_of_project_layer_within_project() {
+        let mut base =
+            parse_layer("daily_template = \"~/daily.template\"", Path::new("/user")).unwrap();
+        let project = parse_layer(
+            r#"
+daily_template = "/home/u/.ssh/id_rsa"
+monthly_template = "+monthly.template"
+yearly_template = "/project/../home/u/.ssh/id_rsa"
+[notebooks.work]
+daily_template = "/etc/passwd"
+"#,
+            Path::new("/project"),
+        )
+        .unwrap();
+
+        merge_project_layer(&mut base, project, Path::new("/project"));
+        let actual: AppConfig = Value::Table(base.clone()).try_into().unwrap();
+
+        assert_eq!(Some("~/daily.template".to_owned()), actual.daily_template);
+        assert_eq!(
+            Some("/project/monthly.template".to_owned()),
+            actual.monthly_template
+        );
+        assert!(actual.yearly_template.is_none());
+        let work = base["notebooks"]["work"].as_table().unwrap();
+        assert!(work.is_empty());
+    }
+
+    #[test]
+    fn should_reject_project_layer_with_data_layout_out_of_project() {
+        let given = r#"
+data_foler = "+journals"
+data_layout = "../.."
+"#;
+        assert!(parse_layer(given, Path::new("/project")).is_err());
+    }
+
+    #[test_case("/project/journals" => true ; "Should accept folder within project")]
+    #[test_case("/project" => true ; "Should accept project folder itself")]
+    #[test_case("/project/../home" => false ; "Should reject escaping the project")]
+    #[test_case("/home/journals" => false ; "Should reject folder outside of project")]
+    #[test_case("journals" => false ; "Should reject relative folder")]
+    fn should_check_if_path_is_within_folder(path: &str) -> bool {
+        is_within_folder(Path::new(path), Path::new("/project"))
+    }
+
+    #[test]
+    fn should_reject_invalid_layer() {
+        assert!(parse_layer("daily_extension = \"docx\"", Path::new("/base")).is_err());
+        assert!(parse_layer(
+            "[notebooks.work]\ndaily_extension = \"docx\"",
+            Path::new("/base")
+        )
+        .is_err());
+    }
+
+    #[test_case("../../{year}" ; "Should reject layout leading out of data folder")]
+    #[test_case("/journals/{year}" ; "Should reject absolute layout")]
+    #[test_case(".archive/{year}" ; "Should reject layout with hidden folder")]
+    fn should_reject_layer_with_invalid_data_layout(layout: &str) {
+        let given = format!("data_layout = {:?}", layout);
+        assert!(parse_layer(&given, Path::new("/base")).is_err());
+        let in_notebook = format!("[notebooks.work]\n{given}");
+        assert!(parse_layer(&in_notebook, Path::new("/base")).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use derive_new::new;
+
+use crate::cli::build_env_name;
+use crate::cli::edit_argument::EditCommonArgs;
+use crate::core::app_options::{self, AppOptions};
+use crate::core::file_access;
+use crate::prelude::*;
+
+use super::AppConfig;
+
+/// Shown as value of an option which is not given by any source.
+const NOT_SET: &str = "<not set>";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Where the value of an option comes from.
+pub enum ValueSource {
+    Cli,
+    Env,
+    File,
+    Default,
+}
+
+impl Display for ValueSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Cli => "CLI",
+            Self::Env => "env",
+            Self::File => "file",
+            Self::Default => "default",
+        };
+        write!(f, "{label}")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Getters, new)]
+#[getset(get = "pub")]
+/// Value of one option which is used by the app together with its source.
+pub struct EffectiveValue {
+    name: String,
+    value: String,
+    source: ValueSource,
+}
+
+/// Returns the values of data path, config path, loaded config files, notebook, editor,
+/// templates and placeholders the app would use together with the source of every value.
+/// ## Errors
+/// - Config file could not be loaded in strict mode.
+/// - Default paths of the os could not be determined.
+pub fn collect_effective_config(option: &AppOptions) -> AppResult<Vec<EffectiveValue>> {
+    let general = option.general();
+    let config = option.load_config()?;
+    let mut values = Vec::new();
+
+    values.push(EffectiveValue::new(
+        "data_path".to_owned(),
+        path_to_string(&option.get_data_path()?),
+        data_path_source(option)?,
+    ));
+    values.push(EffectiveValue::new(
+        "config_path".to_owned(),
+        path_to_string(&AppConfig::resolve_conf_folder(option)?),
+        source_of_given(general.config_path(), build_env_name!(CONFIG_PATH)),
+    ));
+    let layer_files = config.map(|loaded| loaded.layer_files().as_slice());
+    for layer_file in layer_files.into_iter().flatten() {
+        values.push(EffectiveValue::new(
+            "config_file".to_owned(),
+            path_to_string(layer_file),
+            ValueSource::File,
+        ));
+    }
+    values.push(effective_notebook(option, config));
+    values.push(effective_editor(config));
+
+    for (name, template) in [
+        ("daily_template", config.map(AppConfig::daily_template)),
+        ("monthly_template", config.map(AppConfig::monthly_template)),
+        ("yearly_template", config.map(AppConfig::yearly_template)),
+    ] {
+        let resolved = config
+            .zip(template)
+            .and_then(|(loaded, template)| template.try_to_resolved_path(loaded));
+        values.push(match resolved {
+            Some(path) => {
+                EffectiveValue::new(name.to_owned(), path_to_string(&path), ValueSource::File)
+            }
+            None => EffectiveValue::new(name.to_owned(), NOT_SET.to_owned(), ValueSource::Default),
+        });
+    }
+
+    let placeholders = config.and_then(|loaded| loaded.placeholders.as_ref());
+    for placeholder in placeholders.into_iter().flatten() {
+        let value = if placeholder.is_command().unwrap_or(false) {
+            format!("{} (command)", placeholder.value())
+        } else {
+            placeholder.value().clone()
+        };
+        values.push(EffectiveValue::new(
+            format!("placeholders.{}", placeholder.key()),
+            value,
+            ValueSource::File,
+        ));
+    }
+
+    Ok(values)
+}
+
+/// CLI and environment variable are merged by clap into one value.
+/// The value stems from the environment variable if it is equal to it.
+fn source_of_given(given: &Option<String>, env_name: &str) -> ValueSource {
+    match given {
+        Some(value) if app_options::get_from_env(env_name).as_ref() == Some(value) => {
+            ValueSource::Env
+        }
+        Some(_) => ValueSource::Cli,
+        None => ValueSource::Default,
+    }
+}
+
+fn data_path_source(option: &AppOptions) -> AppResult<ValueSource> {
+    let given = option.general().data_path();
+    if option.data_path_from_selected_notebook()?.is_some()
+        || (given.is_none() && option.data_path_from_config()?.is_some())
+    {
+        Ok(ValueSource::File)
+    } else {
+        Ok(source_of_given(given, build_env_name!(DATA_PATH)))
+    }
+}
+
+fn effective_notebook(option: &AppOptions, config: Option<&AppConfig>) -> EffectiveValue {
+    let given = option.general().notebook();
+    let selected = config.and_then(|loaded| loaded.selected_notebook().clone());
+    let (value, source) = match selected {
+        Some(name) if given.is_some() => (name, source_of_given(given, build_env_name!(NOTEBOOK))),
+        Some(name) => (name, ValueSource::File),
+        None => (NOT_SET.to_owned(), ValueSource::Default),
+    };
+
+    EffectiveValue::new("notebook".to_owned(), value, source)
+}
+
+fn effective_editor(config: Option<&AppConfig>) -> EffectiveValue {
+    let (value, source) = if let Some(from_env) = app_options::get_from_env(build_env_name!(EDITOR))
+    {
+        (from_env, ValueSource::Env)
+    } else if let Some(from_file) = config.and_then(|loaded| loaded.editor().clone()) {
+        (from_file, ValueSource::File)
+    } else {
+        (
+            EditCommonArgs::DEFAUTL_EDITOR.to_owned(),
+            ValueSource::Default,
+        )
+    };
+
+    EffectiveValue::new("editor".to_owned(), value, source)
+}
+
+fn path_to_string(path: &Path) -> String {
+    file_access::resolve_path(path)
+        .to_string_lossy()
+        .to_string()
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+use crate::core::constants::DEFAULT_PASSPHRASE_ENV;
+
+use super::PatchFromConfig;
+
+#[derive(Deserialize, Default, Debug, Getters)]
+/// If present in the config file then journals are stored encrypted in the data folder.
+/// Either a key file or a passphrase from an environment variable is used for encryption.
+pub struct EncryptionConfig {
+    /// Path to a file with an age identity aka secret key. Takes precedence over a passphrase.
+    key_file: Option<String>,
+    #[getset(get = "pub")]
+    /// Name of the environment variable which holds the passphrase.
+    passphrase_env: Option<String>,
+}
+
+impl EncryptionConfig {
+    pub fn key_file(&self) -> PatchFromConfig {
+        PatchFromConfig::new(self.key_file.clone())
+    }
+
+    /// Returns name of environment variable with the passphrase.
+    /// Falls back to the default name if none is given in the config file.
+    pub fn resolve_passphrase_env(&self) -> &str {
+        self.passphrase_env
+            .as_deref()
+            .unwrap_or(DEFAULT_PASSPHRASE_ENV)
+    }
+}
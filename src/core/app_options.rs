@@ -46,16 +46,33 @@ impl AppOptions {
         Self::init(args.args().clone(), None)
     }
 
+    /// Returns the same options but with the given notebook selected.
+    pub fn for_notebook(&self, name: &str) -> Self {
+        let mut general = self.general.clone();
+        general.set_notebook(Some(name.to_owned()));
+        Self::init(general, self.debug.clone())
+    }
+
     pub fn load_config(&self) -> AppResult<Option<&AppConfig>> {
         self.config
             .get_or_try_init(|| AppConfig::try_from_file_system(self))
             .map(|to_ref| to_ref.as_ref())
     }
 
+    /// Returns the folder with the journals.
+    /// The data path is taken from the first source which provides one:
+    /// notebook selected via CLI or environment variable, CLI argument, environment variable,
+    /// config file and then the default folder of the os.
     pub fn get_data_path(&self) -> AppResult<PathBuf> {
-        let data_path = if let Some(from_cli_env) = self.general().data_path() {
+        let data_path = if let Some(from_notebook) = self.data_path_from_selected_notebook()? {
+            debug!("Using path from selected notebook as data path");
+            Ok(from_notebook)
+        } else if let Some(from_cli_env) = self.general().data_path() {
             debug!("Using path from cli or env var as data path");
             Ok(file_access::resolve_str_as_path(from_cli_env))
+        } else if let Some(from_config) = self.data_path_from_config()? {
+            debug!("Using path from config file as data path");
+            Ok(from_config)
         } else {
             debug!("Using local data path from os");
             file_access::fetch_data_path(self)
@@ -66,6 +83,28 @@ impl AppOptions {
         Ok(data_path)
     }
 
+    /// Returns the data folder of the notebook selected via CLI or environment variable
+    /// if this notebook has its own data folder.
+    /// The data folder of the default notebook is treated like any other option of the config file.
+    pub fn data_path_from_selected_notebook(&self) -> AppResult<Option<PathBuf>> {
+        if self.general().notebook().is_none() {
+            return Ok(None);
+        }
+
+        Ok(self
+            .load_config()?
+            .and_then(|config| config.notebook_data_folder()))
+    }
+
+    /// Returns the data folder given by the config file if there is any.
+    pub fn data_path_from_config(&self) -> AppResult<Option<PathBuf>> {
+        let from_config = self
+            .load_config()?
+            .and_then(|config| config.data_foler().try_to_resolved_path(config));
+
+        Ok(from_config)
+    }
+
     /// If true is return then the user local files are to be used even during development
     pub fn use_prod_local_share(&self) -> bool {
         self.get_debug_flag(|debug_args| debug_args.user_local_share_data())
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use chrono::NaiveTime;
+use date_validation_types::ValidatedDate;
+
+use crate::core::app_options::AppOptions;
+use crate::core::date_models::JournalKind;
+use crate::prelude::*;
+
+use super::dates_names::{
+    with_journal_of_kind, DateNameForFile, InitialabeFromTemplate, ResolvePlaceholders,
+};
+use super::{journal_store, markdown_sections, write_actions};
+
+/// Returns the given text prefixed with the given time like "14:05 text".
+pub fn timestamped(text: &str, at: NaiveTime) -> String {
+    format!("{} {}", at.format("%H:%M"), text)
+}
+
+/// Appends the given entry to the journal of the given kind which contains the given date.
+/// The entry is inserted as the last line under the given section heading.
+/// If no section is given then the option append_section of the config file is used.
+/// Without any section the entry is appended at the end of the journal.
+/// A missing journal is created from its template first.
+/// Returns the path of the changed journal.
+/// ## Errors
+/// - The section is not a markdown heading like "## Log".
+/// - The journal is only found in an archive folder.
+/// - The journal could not be read or written.
+pub fn append_to_journal(
+    date: ValidatedDate,
+    kind: JournalKind,
+    entry: &str,
+    section: Option<&str>,
+    option: &AppOptions,
+) -> AppResult<PathBuf> {
+    let section = match section {
+        Some(given) => Some(given.to_owned()),
+        None => option
+            .load_config()?
+            .and_then(|config| config.append_section().clone()),
+    };
+    if let Some(heading) = section.as_deref() {
+        ensure_heading(heading)?;
+    }
+
+    with_journal_of_kind!(kind, date, |journal| append_to(
+        journal,
+        entry,
+        section.as_deref(),
+        option
+    ))
+}
+
+fn ensure_heading(section: &str) -> AppResult {
+    if markdown_sections::heading_level(section.trim()).is_none() {
+        bail!(
+            "Section {} is not a markdown heading like \"## Log\"",
+            section
+        );
+    }
+
+    Ok(())
+}
+
+fn append_to<T>(
+    journal: T,
+    entry: &str,
+    section: Option<&str>,
+    option: &AppOptions,
+) -> AppResult<PathBuf>
+where
+    T: DateNameForFile + InitialabeFromTemplate + ResolvePlaceholders,
+{
+    let (path, existing) = write_actions::find_journal_to_change(&journal, option)?;
+    let content = write_actions::existing_or_template(&journal, existing, option)?;
+
+    let entry = entry.trim_end();
+    let new_content = match section {
+        Some(heading) => markdown_sections::insert_into_section(&content, heading, entry),
+        None => markdown_sections::append_at_end(&content, entry),
+    };
+    journal_store::write_journal(&path, &new_content, option)?;
+
+    Ok(path)
+}
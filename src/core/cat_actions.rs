@@ -0,0 +1,232 @@
+use std::path::PathBuf;
+
+use chrono::{Months, NaiveDate};
+use derive_new::new;
+
+use crate::core::app_options::AppOptions;
+use crate::core::constants::{CAT_HEADER_DATE, CAT_HEADER_KIND, CAT_HEADER_NAME, DEFAULT_CAT_HEADER};
+use crate::core::date_models::find_by::{FindByRange, FindByYearMonthDay};
+use crate::core::date_models::JournalKind;
+use crate::prelude::*;
+
+use super::dates_names::{
+    yearly_name::YearlyName, DailyName, DateComponents, DateNameForFile, MonthlyName,
+};
+use super::{file_access, journal_store};
+
+#[derive(Debug, Getters, CopyGetters, new)]
+/// Journal selected by a range with its content.
+pub struct JournalWithContent {
+    #[getset(get_copy = "pub")]
+    kind: JournalKind,
+    #[getset(get_copy = "pub")]
+    components: DateComponents,
+    #[getset(get = "pub")]
+    path: PathBuf,
+    #[getset(get = "pub")]
+    content: String,
+}
+
+impl JournalWithContent {
+    /// Returns the date of the journal like 2023-03-10 for daily, 2023-03 for monthly and
+    /// 2023 for yearly journals.
+    pub fn date_text(&self) -> String {
+        let components = self.components;
+        match (components.month(), components.day()) {
+            (Some(month), Some(day)) => format!("{:04}-{:02}-{:02}", components.year(), month, day),
+            (Some(month), None) => format!("{:04}-{:02}", components.year(), month),
+            _ => format!("{:04}", components.year()),
+        }
+    }
+
+    /// Returns the given format for a header with its placeholders replaced by the values of
+    /// this journal. See [`DEFAULT_CAT_HEADER`] for the placeholders.
+    pub fn header(&self, format: &str) -> String {
+        let name = self
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        format
+            .replace(CAT_HEADER_DATE, &self.date_text())
+            .replace(CAT_HEADER_KIND, &self.kind.to_string())
+            .replace(CAT_HEADER_NAME, &name)
+    }
+}
+
+/// Returns the header format given via CLI, via the config file or the default one.
+pub fn resolve_header_format(given: Option<&str>, option: &AppOptions) -> AppResult<String> {
+    if let Some(given) = given {
+        return Ok(given.to_owned());
+    }
+
+    let from_config = option
+        .load_config()?
+        .and_then(|config| config.cat_header().clone());
+
+    Ok(from_config.unwrap_or_else(|| DEFAULT_CAT_HEADER.to_owned()))
+}
+
+/// Returns all journals of the given kinds in chronological order with their content.
+/// A journal is selected if its day, month or year overlaps with the given range of dates and
+/// it matches the given filter. Parts of the filter which a kind of journal does not have are
+/// ignored for it. Journals of the same start are ordered as yearly, monthly and daily.
+/// Journals of archive folders are included.
+/// ## Errors
+/// - A selected journal could not be read.
+pub fn fetch_journals_in_range(
+    kinds: &[JournalKind],
+    range: &FindByRange<NaiveDate>,
+    filter: &FindByYearMonthDay,
+    option: &AppOptions,
+) -> AppResult<Vec<JournalWithContent>> {
+    let mut selected = Vec::new();
+    for kind in kinds {
+        let found = match kind {
+            JournalKind::Daily => fetch_kind_with_paths::<DailyName>(option)?,
+            JournalKind::Monthly => fetch_kind_with_paths::<MonthlyName>(option)?,
+            JournalKind::Yearly => fetch_kind_with_paths::<YearlyName>(option)?,
+        };
+        selected.extend(
+            found
+                .into_iter()
+                .filter(|(components, _)| {
+                    overlaps_range(components, range) && matches_filter(components, filter)
+                })
+                .map(|(components, path)| (*kind, components, path)),
+        );
+    }
+
+    selected.sort_by(|left, right| {
+        period_of(&left.1)
+            .0
+            .cmp(&period_of(&right.1).0)
+            .then_with(|| rank_of(left.0).cmp(&rank_of(right.0)))
+    });
+
+    let mut journals = Vec::with_capacity(selected.len());
+    for (kind, components, path) in selected {
+        if let Some(content) = journal_store::read_journal(&path, option)? {
+            journals.push(JournalWithContent::new(kind, components, path, content));
+        }
+    }
+
+    Ok(journals)
+}
+
+/// Returns the journals of one kind in the data folder and the archive folders.
+fn fetch_kind_with_paths<T>(option: &AppOptions) -> AppResult<Vec<(DateComponents, PathBuf)>>
+where
+    T: DateNameForFile,
+{
+    Ok(file_access::fetch_entries_with_archive::<T>(option)?
+        .into_iter()
+        .map(|(journal, path)| (journal.date_components(), path))
+        .collect())
+}
+
+/// Returns the first and the last day of the day, month or year of a journal.
+fn period_of(components: &DateComponents) -> (NaiveDate, NaiveDate) {
+    let year = components.year() as i32;
+    let start = NaiveDate::from_ymd_opt(
+        year,
+        components.month().unwrap_or(1),
+        components.day().unwrap_or(1),
+    )
+    .unwrap_or(NaiveDate::MIN);
+    let end = match (components.month(), components.day()) {
+        (Some(_), Some(_)) => Some(start),
+        (Some(_), None) => start
+            .checked_add_months(Months::new(1))
+            .and_then(|next| next.pred_opt()),
+        _ => NaiveDate::from_ymd_opt(year, 12, 31),
+    };
+
+    (start, end.unwrap_or(NaiveDate::MAX))
+}
+
+fn overlaps_range(components: &DateComponents, range: &FindByRange<NaiveDate>) -> bool {
+    let (start, end) = period_of(components);
+    let after_from = range.from().map_or(true, |from| from <= end);
+    let before_to = range.to().map_or(true, |to| start <= to);
+    after_from && before_to
+}
+
+fn matches_filter(components: &DateComponents, filter: &FindByYearMonthDay) -> bool {
+    let year_matches = filter
+        .year()
+        .map_or(true, |year| u32::from(year) == components.year());
+    let month_matches = filter
+        .month()
+        .zip(components.month())
+        .map_or(true, |(month, own)| u32::from(month) == own);
+    let day_matches = filter
+        .day()
+        .zip(components.day())
+        .map_or(true, |(day, own)| u32::from(day) == own);
+
+    year_matches && month_matches && day_matches
+}
+
+fn rank_of(kind: JournalKind) -> u8 {
+    match kind {
+        JournalKind::Yearly => 0,
+        JournalKind::Monthly => 1,
+        JournalKind::Daily => 2,
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use test_case::test_case;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test_case(DateComponents::new(2023, Some(2), Some(5)) => (date(2023, 2, 5), date(2023, 2, 5)) ; "Should be one day for daily")]
+    #[test_case(DateComponents::new(2024, Some(2), None) => (date(2024, 2, 1), date(2024, 2, 29)) ; "Should be whole month for monthly")]
+    #[test_case(DateComponents::new(2023, None, None) => (date(2023, 1, 1), date(2023, 12, 31)) ; "Should be whole year for yearly")]
+    fn should_return_period_of_journal(components: DateComponents) -> (NaiveDate, NaiveDate) {
+        period_of(&components)
+    }
+
+    #[test_case(DateComponents::new(2023, Some(3), None), Some(date(2023, 3, 20)), None => true ; "Should overlap with month ending after from")]
+    #[test_case(DateComponents::new(2023, Some(2), None), Some(date(2023, 3, 1)), None => false ; "Should not overlap with month before from")]
+    #[test_case(DateComponents::new(2023, None, None), None, Some(date(2023, 1, 1)) => true ; "Should overlap with year starting at to")]
+    #[test_case(DateComponents::new(2023, Some(1), Some(2)), Some(date(2023, 1, 3)), Some(date(2023, 1, 4)) => false ; "Should not overlap with day outside")]
+    fn should_check_overlap_with_range(
+        components: DateComponents,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> bool {
+        overlaps_range(&components, &FindByRange::new(from, to))
+    }
+
+    #[test_case(DateComponents::new(2023, Some(3), Some(10)) => true ; "Should match daily of filter")]
+    #[test_case(DateComponents::new(2023, Some(3), None) => true ; "Should ignore day for monthly")]
+    #[test_case(DateComponents::new(2023, Some(4), None) => false ; "Should not match other month")]
+    #[test_case(DateComponents::new(2023, None, None) => true ; "Should ignore month and day for yearly")]
+    fn should_match_filter_of_parts_of_journal(components: DateComponents) -> bool {
+        let filter = FindByYearMonthDay::new(Some(2023), Some(3), Some(10)).unwrap();
+        matches_filter(&components, &filter)
+    }
+
+    #[test]
+    fn should_create_header_from_format() {
+        let given = JournalWithContent::new(
+            JournalKind::Monthly,
+            DateComponents::new(2023, Some(3), None),
+            PathBuf::from("/data/2023_03_monthly.md"),
+            String::new(),
+        );
+
+        assert_eq!(
+            "# 2023-03 (monthly) 2023_03_monthly.md",
+            given.header("# {date} ({kind}) {name}")
+        );
+    }
+}
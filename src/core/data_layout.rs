@@ -0,0 +1,111 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::core::constants::{LAYOUT_DAY, LAYOUT_MONTH, LAYOUT_YEAR};
+use crate::prelude::*;
+
+use super::dates_names::DateComponents;
+
+/// Returns the folder relative to the data folder in which a journal with the given date
+/// components resides.
+/// A segment of the layout with a placeholder, which the journal can not fill, is left out
+/// together with all following segments.
+/// Example: a yearly journal is placed in "2023" with the layout "{year}/{month}".
+pub fn folder_for(layout: &str, components: &DateComponents) -> PathBuf {
+    let mut folder = PathBuf::new();
+
+    for segment in segments(layout) {
+        match resolve_segment(segment, components) {
+            Some(resolved) => folder.push(resolved),
+            None => break,
+        }
+    }
+
+    folder
+}
+
+/// Fails if the layout could place journals outside of the data folder
+/// or within hidden folders which are skipped while searching for journals.
+/// ## Errors
+/// - Layout is an absolute path or has a segment like "..".
+/// - Layout has a segment starting with a ".".
+pub fn ensure_valid_layout(layout: &str) -> AppResult {
+    if Path::new(layout.trim()).has_root() {
+        bail!("Data layout {} must be relative to the data folder", layout);
+    }
+
+    for segment in segments(layout) {
+        let leaves_data_folder = Path::new(segment)
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)));
+        if leaves_data_folder {
+            bail!(
+                "Data layout {} must not contain the folder {} which leads outside of the data folder",
+                layout,
+                segment
+            );
+        }
+        if segment.starts_with('.') {
+            bail!(
+                "Data layout {} must not contain the hidden folder {}. Journals in it would not be found",
+                layout,
+                segment
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn segments(layout: &str) -> impl Iterator<Item = &str> {
+    layout
+        .split('/')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+}
+
+fn resolve_segment(segment: &str, components: &DateComponents) -> Option<String> {
+    let mut resolved = segment.replace(LAYOUT_YEAR, &format!("{:04}", components.year()));
+
+    if resolved.contains(LAYOUT_MONTH) {
+        let month = components.month()?;
+        resolved = resolved.replace(LAYOUT_MONTH, &format!("{:02}", month));
+    }
+    if resolved.contains(LAYOUT_DAY) {
+        let day = components.day()?;
+        resolved = resolved.replace(LAYOUT_DAY, &format!("{:02}", day));
+    }
+
+    Some(resolved)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("{year}/{month}", (2023, Some(3), Some(9)) => PathBuf::from("2023/03") ; "Should place daily in year and month")]
+    #[test_case("{year}/{month}", (2023, Some(3), None) => PathBuf::from("2023/03") ; "Should place monthly in year and month")]
+    #[test_case("{year}/{month}", (2023, None, None) => PathBuf::from("2023") ; "Should place yearly only in year")]
+    #[test_case("journals/{year}-{month}/", (812, Some(11), Some(2)) => PathBuf::from("journals/0812-11") ; "Should resolve several placeholders in one segment")]
+    #[test_case("{year}/{month}/{day}", (2023, Some(3), Some(9)) => PathBuf::from("2023/03/09") ; "Should place daily in own folder for day")]
+    #[test_case("", (2023, Some(3), Some(9)) => PathBuf::new() ; "Should place nothing without layout")]
+    fn should_resolve_folder(layout: &str, (y, m, d): (u32, Option<u32>, Option<u32>)) -> PathBuf {
+        folder_for(layout, &DateComponents::new(y, m, d))
+    }
+
+    #[test_case("{year}/{month}" ; "Should accept folders with placeholders")]
+    #[test_case("journals/{year}-{month}/" ; "Should accept fixed folder and trailing slash")]
+    #[test_case("" ; "Should accept empty layout")]
+    fn should_accept_layout(layout: &str) {
+        ensure_valid_layout(layout).unwrap();
+    }
+
+    #[test_case("../../{year}" ; "Should reject parent folder")]
+    #[test_case("{year}/../{month}" ; "Should reject parent folder in middle")]
+    #[test_case("/home/{year}" ; "Should reject absolute path")]
+    #[test_case(".hidden/{year}" ; "Should reject hidden folder")]
+    #[test_case("{year}/./{month}" ; "Should reject current folder")]
+    fn should_reject_layout(layout: &str) {
+        assert!(ensure_valid_layout(layout).is_err());
+    }
+}
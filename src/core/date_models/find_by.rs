@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use date_validation_types::{ValidatedYear, ValidatedMonth, ValidatedDay};
+use derive_new::new;
 
 #[derive(CopyGetters, Debug)]
 #[getset(get_copy = "pub")]
@@ -19,6 +20,32 @@ pub enum FindByMonthInYear {
     },
 }
 
+/// Inclusive range of journals. A missing bound means there is no limit in this direction.
+#[derive(Debug, PartialEq, Eq, Getters, new)]
+#[getset(get = "pub")]
+pub struct FindByRange<T> {
+    from: Option<T>,
+    to: Option<T>,
+}
+
+impl<T> FindByRange<T>
+where
+    T: Ord,
+{
+    pub fn contains(&self, to_check: &T) -> bool {
+        let after_from = self.from.as_ref().map_or(true, |from| from <= to_check);
+        let before_to = self.to.as_ref().map_or(true, |to| to_check <= to);
+        after_from && before_to
+    }
+
+    pub fn map<U>(self, mapper: impl Fn(T) -> U) -> FindByRange<U> {
+        FindByRange {
+            from: self.from.map(&mapper),
+            to: self.to.map(&mapper),
+        }
+    }
+}
+
 impl FindByYearMonthDay {
     pub fn new(y_opt: Option<u32>, m_opt: Option<u32>, d_opt: Option<u32>) -> AppResult<Self> {
         let mut valid_d_opt = None;
@@ -50,6 +77,21 @@ impl FindByYearMonthDay {
 mod testing {
     use super::*;
 
+    mod find_by_range {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case(Some(2), Some(4), 3 => true ; "Should contain value between bounds")]
+        #[test_case(Some(2), Some(4), 4 => true ; "Should contain upper bound")]
+        #[test_case(Some(2), Some(4), 5 => false ; "Should not contain value after upper bound")]
+        #[test_case(Some(2), None, 1 => false ; "Should not contain value before lower bound")]
+        #[test_case(None, Some(4), 1 => true ; "Should contain everything before upper bound")]
+        #[test_case(None, None, 100 => true ; "Should contain everything without bounds")]
+        fn should_check_if_in_range(from: Option<u32>, to: Option<u32>, given: u32) -> bool {
+            FindByRange::new(from, to).contains(&given)
+        }
+    }
+
     mod find_by_year_month_day {
         use super::*;
         use test_case::test_case;
@@ -0,0 +1,25 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+/// Kind of journal which is selected via a date.
+/// The monthly and yearly journal are the ones which contain the date.
+pub enum JournalKind {
+    #[default]
+    Daily,
+    Monthly,
+    Yearly,
+}
+
+impl Display for JournalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Daily => "daily",
+            Self::Monthly => "monthly",
+            Self::Yearly => "yearly",
+        };
+        write!(f, "{label}")
+    }
+}
@@ -1,5 +1,9 @@
 pub mod find_by;
+pub mod journal_kind;
+pub mod navigate_by;
 pub mod open_by;
+pub use journal_kind::JournalKind;
+pub use navigate_by::NavigateBy;
 use crate::{
     prelude::*,
     core::constants::{MONTH_LOWER_BOUND, MONTH_UPPER_BOUND, DAY_LOWER_BOUND, DAY_UPPER_BOUND},
@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Selects one of the existing journals instead of a date.
+pub enum NavigateBy {
+    /// Most recent existing journal.
+    Last,
+    /// Nearest existing journal before a date.
+    Previous,
+    /// Nearest existing journal after a date.
+    Next,
+    /// Any existing journal.
+    Random,
+}
@@ -1,4 +1,4 @@
-use chrono::{NaiveDate, Days};
+use chrono::{NaiveDate, Days, Local, Datelike};
 
 use crate::AppResult;
 use date_validation_types::{ValidatedDate, ValidatedMonth, ValidatedYear};
@@ -59,3 +59,25 @@ pub enum OpenByMonthInYear {
         year: ValidatedYear,
     },
 }
+
+impl OpenByMonthInYear {
+    /// Returns the selected month in the year of the given date.
+    pub fn from_date(date: ValidatedDate) -> AppResult<Self> {
+        Ok(Self::WithYear {
+            month: date.month().try_into()?,
+            year: date.year().try_into()?,
+        })
+    }
+
+    /// Returns the first day of the selected month.
+    pub fn first_day(&self) -> AppResult<ValidatedDate> {
+        let now = Local::now().date_naive();
+        let (year, month) = match self {
+            Self::CurrentMonth => (now.year() as u32, now.month()),
+            Self::InCurrentYear(month) => (now.year() as u32, (*month).into()),
+            Self::WithYear { month, year } => ((*year).into(), (*month).into()),
+        };
+
+        Ok(ValidatedDate::from_ymd(year, month, 1)?)
+    }
+}
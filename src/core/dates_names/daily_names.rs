@@ -4,6 +4,8 @@ use std::borrow::Cow;
 use std::str::FromStr;
 
 use crate::core::app_config::AppConfig;
+use crate::core::app_options::AppOptions;
+use crate::core::on_this_day_actions;
 use crate::core::constants::*;
 use chrono::prelude::*;
 use thiserror::Error;
@@ -21,6 +23,45 @@ impl DateNameForFile for DailyName {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn with_extension(&self, ext: JournalExtension) -> Self {
+        let name = Self::create_name_from_date(self.date.into(), ext.as_str());
+        Self {
+            date: self.date,
+            name,
+        }
+    }
+
+    fn choose_extension(&self, app_config: &AppConfig) -> Option<JournalExtension> {
+        app_config.daily_extension()
+    }
+
+    fn date_components(&self) -> DateComponents {
+        DateComponents::new(
+            self.date.year(),
+            Some(self.date.month()),
+            Some(self.date.day()),
+        )
+    }
+
+    fn configured_pattern(app_config: &AppConfig) -> Option<&NamingPattern> {
+        app_config.daily_name_pattern().as_ref()
+    }
+
+    fn from_pattern(pattern: &NamingPattern, name: &str) -> Option<Self> {
+        let components = pattern.parse(name)?;
+        let date = ValidatedDate::new(
+            components.year().try_into().ok()?,
+            components.month()?.try_into().ok()?,
+            components.day()?.try_into().ok()?,
+        )
+        .ok()?;
+
+        Some(Self {
+            date,
+            name: name.to_owned(),
+        })
+    }
 }
 
 impl DailyName {
@@ -43,7 +84,7 @@ impl DailyName {
     }
 
     fn to_format(year: u32, month: u32, day: u32, ext: &str) -> String {
-        format!("{year}{DIGIT_SEP}{month:02}{DIGIT_SEP}{day:02}{DIGIT_SEP}{DAILY_INFIX}.{ext}",)
+        format!("{year:04}{DIGIT_SEP}{month:02}{DIGIT_SEP}{day:02}{DIGIT_SEP}{DAILY_INFIX}.{ext}",)
     }
 }
 
@@ -56,6 +97,20 @@ impl ResolvePlaceholders for DailyName {
             _ => Cow::Borrowed(to_resolve),
         }
     }
+
+    fn resolve_from_journals(
+        &self,
+        to_resolve: &str,
+        option: &AppOptions,
+    ) -> AppResult<Option<String>> {
+        match to_resolve {
+            ON_THIS_DAY_VAR_NAME => {
+                let links = on_this_day_actions::on_this_day_as_links(self, option)?;
+                Ok(Some(links))
+            }
+            _ => Ok(None),
+        }
+    }
 }
 
 impl InitialabeFromTemplate for DailyName {
@@ -84,7 +139,7 @@ impl HasMonth for DailyName {
 
 impl PartialOrd for DailyName {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.date.partial_cmp(&other.date)
+        Some(self.cmp(other))
     }
 }
 
@@ -113,7 +168,16 @@ impl FromStr for DailyName {
             (Some(_), None, None) => Err(ParseDailyNameError::MissingYear),
             _ => unreachable!(),
         }?;
-        let validated = Self::new(y_parsed, m_parsed, d_parsed, MD_EXT)
+
+        let ext = match (splits.next(), splits.next()) {
+            (Some(label_with_ext), None) => {
+                JournalExtension::strip_label(label_with_ext, DAILY_INFIX)
+                    .ok_or(ParseDailyNameError::InvalidLabelOrExtension)
+            }
+            _ => Err(ParseDailyNameError::InvalidLabelOrExtension),
+        }?;
+
+        let validated = Self::new(y_parsed, m_parsed, d_parsed, ext.as_str())
             .map_err(|_| ParseDailyNameError::InvalidDate)?;
 
         Ok(validated)
@@ -151,4 +215,6 @@ pub enum ParseDailyNameError {
     MissingMonth,
     #[error("Year, month and day form a invalid date")]
     InvalidDate,
+    #[error("Name of daily does not end with daily and a supported extension")]
+    InvalidLabelOrExtension,
 }
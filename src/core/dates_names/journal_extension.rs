@@ -0,0 +1,71 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+/// File extension of a journal. It is configurable per kind of journal.
+pub enum JournalExtension {
+    #[default]
+    Md,
+    Org,
+    Txt,
+    Adoc,
+}
+
+impl JournalExtension {
+    /// Every extension a journal can have. Journals with these extensions are recognized
+    /// regardless of which extension is configured.
+    pub const ALL: [JournalExtension; 4] = [Self::Md, Self::Org, Self::Txt, Self::Adoc];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Md => "md",
+            Self::Org => "org",
+            Self::Txt => "txt",
+            Self::Adoc => "adoc",
+        }
+    }
+
+    /// Returns the extension if `label_with_ext` consists of the `label`, a dot and
+    /// a supported extension.
+    /// Example: "daily.org" with label "daily" returns the extension org.
+    pub fn strip_label(label_with_ext: &str, label: &str) -> Option<Self> {
+        let ext = label_with_ext.strip_prefix(label)?.strip_prefix('.')?;
+        ext.parse().ok()
+    }
+}
+
+impl Display for JournalExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for JournalExtension {
+    type Err = AppError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|ext| ext.as_str() == s)
+            .ok_or_else(|| anyhow!("{} is not a supported extension for journals", s))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("daily.md", "daily" => Some(JournalExtension::Md))]
+    #[test_case("daily.org", "daily" => Some(JournalExtension::Org))]
+    #[test_case("monthly.adoc", "monthly" => Some(JournalExtension::Adoc))]
+    #[test_case("daily.docx", "daily" => None)]
+    #[test_case("daily.txt", "monthly" => None)]
+    #[test_case("dailytxt", "daily" => None)]
+    fn should_strip_label(label_with_ext: &str, label: &str) -> Option<JournalExtension> {
+        JournalExtension::strip_label(label_with_ext, label)
+    }
+}
@@ -1,17 +1,55 @@
 pub mod daily_names;
+pub mod journal_extension;
 pub mod monthly_name;
+pub mod naming_pattern;
 pub mod yearly_name;
 pub use daily_names::DailyName;
+pub use journal_extension::JournalExtension;
 pub use monthly_name::MonthlyName;
+pub use naming_pattern::NamingPattern;
 pub use crate::core::app_config::PatchFromConfig;
 
 #[cfg(test)]
 pub mod test_daily_names;
 
 use crate::core::app_config::AppConfig;
+use crate::core::app_options::AppOptions;
 use crate::core::constants::{DAY_VAR_NAME, MONTH_VAR_NAME, YEAR_VAR_NAME};
 
 use std::{str::FromStr, borrow::Cow};
+use derive_new::new;
+
+/// Evaluates the given body with the journal of the given [`JournalKind`] bound to the given
+/// name. The journal is the one which contains the given date.
+/// Needed because every kind of journal is its own type.
+///
+/// [`JournalKind`]: crate::core::date_models::JournalKind
+macro_rules! with_journal_of_kind {
+    ($kind:expr, $date:expr, |$journal:ident| $body:expr) => {{
+        let date: date_validation_types::ValidatedDate = $date;
+        match $kind {
+            $crate::core::date_models::JournalKind::Daily => {
+                let $journal = $crate::core::dates_names::DailyName::from(date);
+                $body
+            }
+            $crate::core::date_models::JournalKind::Monthly => {
+                let $journal = $crate::core::dates_names::MonthlyName::from_ym(
+                    date.year(),
+                    date.month(),
+                    $crate::core::constants::MD_EXT,
+                )?;
+                $body
+            }
+            $crate::core::date_models::JournalKind::Yearly => {
+                let $journal = $crate::core::dates_names::yearly_name::YearlyName::new(
+                    date.year().try_into()?,
+                );
+                $body
+            }
+        }
+    }};
+}
+pub(crate) use with_journal_of_kind;
 
 pub trait HasYear {
     fn year(&self) -> u32;
@@ -28,12 +66,59 @@ pub trait HasMonth {
     }
 }
 
+/// Year, month and day of a journal. Month and day are none for kinds of journals without them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CopyGetters, new)]
+#[getset(get_copy = "pub")]
+pub struct DateComponents {
+    year: u32,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
 pub trait ToDateTuple {
     fn to_date_tuple(&self) -> String;
 }
 
 pub trait DateNameForFile: ToDateTuple + FromStr + Ord {
     fn name(&self) -> &str;
+
+    /// Returns the same journal whose file name ends with the given extension.
+    fn with_extension(&self, ext: JournalExtension) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the extension configured for this kind of journal if there is one.
+    fn choose_extension(&self, app_config: &AppConfig) -> Option<JournalExtension>;
+
+    /// Returns the date components which are used to place the journal within the data folder.
+    fn date_components(&self) -> DateComponents;
+
+    /// Returns the naming pattern configured for this kind of journal if there is one.
+    fn configured_pattern(app_config: &AppConfig) -> Option<&NamingPattern>
+    where
+        Self: Sized;
+
+    /// Creates a journal from a file name which follows the given naming pattern.
+    fn from_pattern(pattern: &NamingPattern, name: &str) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns the same journal whose file name is created via the given naming pattern.
+    /// ## Errors
+    /// - The created file name can not be parsed by the same pattern.
+    fn with_pattern(&self, pattern: &NamingPattern, ext: JournalExtension) -> crate::AppResult<Self>
+    where
+        Self: Sized,
+    {
+        let name = pattern.format(&self.date_components(), ext);
+        Self::from_pattern(pattern, &name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "File name {} created by the naming pattern {} can not be parsed by the same pattern",
+                name,
+                pattern
+            )
+        })
+    }
 }
 
 pub trait InitialabeFromTemplate {
@@ -42,4 +127,14 @@ pub trait InitialabeFromTemplate {
 
 pub trait ResolvePlaceholders {
     fn resolve_variable<'a>(&self, to_resolve: &'a str) -> Cow<'a, str>;
+
+    /// Returns the value of a builtin variable which is built from other journals.
+    /// Returns none if the builtin variable is not known for this kind of journal.
+    fn resolve_from_journals(
+        &self,
+        _to_resolve: &str,
+        _option: &AppOptions,
+    ) -> crate::AppResult<Option<String>> {
+        Ok(None)
+    }
 }
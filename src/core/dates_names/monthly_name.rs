@@ -6,7 +6,10 @@ use chrono::{Local, Datelike};
 use date_validation_types::{ValidatedYear, ValidatedMonth};
 use crate::{
     prelude::*,
-    core::{constants::*, date_models::open_by::OpenByMonthInYear},
+    core::{
+        app_options::AppOptions, constants::*, date_models::open_by::OpenByMonthInYear,
+        rollup_actions,
+    },
 };
 
 #[derive(Getters, CopyGetters, PartialEq, Eq, Debug)]
@@ -20,6 +23,32 @@ impl DateNameForFile for MonthlyName {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn with_extension(&self, ext: JournalExtension) -> Self {
+        let name = Self::create_name(self.year.into(), self.month.into(), ext.as_str());
+        Self {
+            name,
+            year: self.year,
+            month: self.month,
+        }
+    }
+
+    fn choose_extension(&self, app_config: &AppConfig) -> Option<JournalExtension> {
+        app_config.monthly_extension()
+    }
+
+    fn date_components(&self) -> DateComponents {
+        DateComponents::new(self.year.into(), Some(self.month.into()), None)
+    }
+
+    fn configured_pattern(app_config: &AppConfig) -> Option<&NamingPattern> {
+        app_config.monthly_name_pattern().as_ref()
+    }
+
+    fn from_pattern(pattern: &NamingPattern, name: &str) -> Option<Self> {
+        let components = pattern.parse(name)?;
+        Self::with_name(components.year(), components.month()?, name.to_owned()).ok()
+    }
 }
 
 impl MonthlyName {
@@ -56,6 +85,10 @@ impl MonthlyName {
         }
     }
 
+    pub fn year_month(&self) -> (ValidatedYear, ValidatedMonth) {
+        (self.year, self.month)
+    }
+
     fn create_name(year: u32, month: u32, ext: &str) -> String {
         format!(
             "{year:04}{0}{month:02}{0}{1}.{ext}",
@@ -72,6 +105,17 @@ impl ResolvePlaceholders for MonthlyName {
             _ => Cow::Borrowed(to_resolve),
         }
     }
+
+    fn resolve_from_journals(
+        &self,
+        to_resolve: &str,
+        option: &AppOptions,
+    ) -> AppResult<Option<String>> {
+        match to_resolve {
+            MONTH_ROLLUP_VAR_NAME => Ok(Some(rollup_actions::month_rollup(self, option)?)),
+            _ => Ok(None),
+        }
+    }
 }
 
 impl InitialabeFromTemplate for MonthlyName {
@@ -115,7 +159,11 @@ impl FromStr for MonthlyName {
         let mut splits = s.trim().split(DIGIT_SEP);
 
         match (splits.next(), splits.next(), splits.next(), splits.next()) {
-            (Some(year), Some(month), Some(_), None) => {
+            (Some(year), Some(month), Some(label_with_ext), None) => {
+                if JournalExtension::strip_label(label_with_ext, MONTHLY_LABEL_IN_NAME).is_none() {
+                    bail!("Name of monthly does not end with monthly and a supported extension")
+                }
+
                 let parsed_year: u32 = year
                     .parse()
                     .map_err(AppError::new)
@@ -164,6 +212,25 @@ mod testing {
 
         assert_eq!(expected, actual);
     }
+    #[test]
+    fn should_parse_from_str_with_other_extension() {
+        let given = "2000_08_monthly.org";
+        let actual: MonthlyName = given
+            .parse()
+            .expect("Parsing should not fail in this test.");
+        let expected = MonthlyName::from_ym(2000, 8, "org").expect("Invalid month given.");
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn should_fail_parse_str_with_unsupported_extension() {
+        let given = "2000_08_monthly.docx";
+        let has_failed = given.parse::<MonthlyName>().is_err();
+
+        assert!(has_failed);
+    }
+
     #[test]
     fn should_fail_parse_str() {
         let given = "2000_08_12_monthly.md";
@@ -0,0 +1,277 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::Deserialize;
+
+use crate::core::constants::{
+    NAME_PATTERN_DAY, NAME_PATTERN_EXT, NAME_PATTERN_MONTH, NAME_PATTERN_YEAR,
+};
+use crate::prelude::*;
+
+use super::{DateComponents, JournalExtension};
+
+const YEAR_WIDTH: usize = 4;
+const MONTH_DAY_WIDTH: usize = 2;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "String")]
+/// Pattern from which the file names of one kind of journal are created and parsed.
+/// Example: the pattern "{yyyy}-{mm}-{dd}.{ext}" leads to the file name "2023-03-09.md".
+pub struct NamingPattern {
+    raw: String,
+    parts: Vec<PatternPart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PatternPart {
+    Literal(String),
+    Year,
+    Month,
+    Day,
+    Ext,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Captured {
+    year: Option<u32>,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
+impl NamingPattern {
+    /// Creates the file name for a journal with the given date components and extension.
+    /// The extension is only used if the pattern contains the placeholder for it.
+    pub fn format(&self, components: &DateComponents, ext: JournalExtension) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                PatternPart::Literal(literal) => literal.clone(),
+                PatternPart::Year => format!("{:04}", components.year()),
+                PatternPart::Month => format!("{:02}", components.month().unwrap_or_default()),
+                PatternPart::Day => format!("{:02}", components.day().unwrap_or_default()),
+                PatternPart::Ext => ext.as_str().to_owned(),
+            })
+            .collect()
+    }
+
+    /// Returns the date components of the given file name if it follows this pattern.
+    /// The date components are not checked for forming a valid date.
+    pub fn parse(&self, name: &str) -> Option<DateComponents> {
+        let captured = match_parts(&self.parts, name, Captured::default())?;
+        Some(DateComponents::new(
+            captured.year?,
+            captured.month,
+            captured.day,
+        ))
+    }
+
+    /// Fails if the pattern does not have exactly the placeholders needed for one kind of journal.
+    /// Every pattern needs a year. The month and day are needed if `with_month` and `with_day`
+    /// are true and must be absent otherwise.
+    pub fn ensure_placeholders(&self, with_month: bool, with_day: bool) -> AppResult {
+        let has = |to_find: &PatternPart| self.parts.iter().any(|part| part == to_find);
+
+        for (placeholder, needed, present) in [
+            (NAME_PATTERN_MONTH, with_month, has(&PatternPart::Month)),
+            (NAME_PATTERN_DAY, with_day, has(&PatternPart::Day)),
+        ] {
+            if needed && !present {
+                bail!(
+                    "Naming pattern {} is missing the placeholder {}",
+                    self.raw,
+                    placeholder
+                );
+            }
+            if !needed && present {
+                bail!(
+                    "Naming pattern {} must not contain the placeholder {}",
+                    self.raw,
+                    placeholder
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn match_parts(parts: &[PatternPart], rest: &str, captured: Captured) -> Option<Captured> {
+    let (part, tail) = match parts.split_first() {
+        Some(next) => next,
+        None => return rest.is_empty().then_some(captured),
+    };
+
+    match part {
+        PatternPart::Literal(literal) => {
+            match_parts(tail, rest.strip_prefix(literal.as_str())?, captured)
+        }
+        PatternPart::Year => {
+            // Years after 9999 have more digits. The shortest fitting width is taken.
+            let max_width = rest.bytes().take_while(u8::is_ascii_digit).count();
+            (YEAR_WIDTH..=max_width).find_map(|width| {
+                let (year, after) = take_digits(rest, width)?;
+                let captured = Captured {
+                    year: Some(year),
+                    ..captured
+                };
+                match_parts(tail, after, captured)
+            })
+        }
+        PatternPart::Month => {
+            let (month, after) = take_digits(rest, MONTH_DAY_WIDTH)?;
+            let captured = Captured {
+                month: Some(month),
+                ..captured
+            };
+            match_parts(tail, after, captured)
+        }
+        PatternPart::Day => {
+            let (day, after) = take_digits(rest, MONTH_DAY_WIDTH)?;
+            let captured = Captured {
+                day: Some(day),
+                ..captured
+            };
+            match_parts(tail, after, captured)
+        }
+        PatternPart::Ext => JournalExtension::ALL
+            .into_iter()
+            .filter_map(|ext| rest.strip_prefix(ext.as_str()))
+            .find_map(|after| match_parts(tail, after, captured)),
+    }
+}
+
+fn take_digits(to_take_from: &str, width: usize) -> Option<(u32, &str)> {
+    let digits = to_take_from.get(..width)?;
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((digits.parse().ok()?, &to_take_from[width..]))
+}
+
+fn split_into_parts(pattern: &str) -> AppResult<Vec<PatternPart>> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut rest = pattern;
+
+    while let Some(next) = rest.chars().next() {
+        let placeholder = [
+            (NAME_PATTERN_YEAR, PatternPart::Year),
+            (NAME_PATTERN_MONTH, PatternPart::Month),
+            (NAME_PATTERN_DAY, PatternPart::Day),
+            (NAME_PATTERN_EXT, PatternPart::Ext),
+        ]
+        .into_iter()
+        .find(|(token, _)| rest.starts_with(token));
+
+        match placeholder {
+            Some((token, part)) => {
+                if !literal.is_empty() {
+                    parts.push(PatternPart::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(part);
+                rest = &rest[token.len()..];
+            }
+            None => {
+                if next == '{' || next == '}' {
+                    bail!("Naming pattern {} contains an unknown placeholder", pattern);
+                }
+                if std::path::is_separator(next) {
+                    bail!(
+                        "Naming pattern {} must not contain folders. Use the option data_layout for folders",
+                        pattern
+                    );
+                }
+                literal.push(next);
+                rest = &rest[next.len_utf8()..];
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        parts.push(PatternPart::Literal(literal));
+    }
+
+    if !parts.contains(&PatternPart::Year) {
+        bail!(
+            "Naming pattern {} is missing the placeholder {}",
+            pattern,
+            NAME_PATTERN_YEAR
+        );
+    }
+
+    Ok(parts)
+}
+
+impl TryFrom<String> for NamingPattern {
+    type Error = AppError;
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        let parts = split_into_parts(&raw)?;
+        Ok(Self { raw, parts })
+    }
+}
+
+impl FromStr for NamingPattern {
+    type Err = AppError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.to_owned().try_into()
+    }
+}
+
+impl Display for NamingPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("{yyyy}-{mm}-{dd}.md", (2023, Some(3), Some(9)), JournalExtension::Org => "2023-03-09.md" ; "Should keep literal extension")]
+    #[test_case("{yyyy}-{mm}-{dd}.{ext}", (2023, Some(3), Some(9)), JournalExtension::Org => "2023-03-09.org" ; "Should insert extension")]
+    #[test_case("{yyyy}{mm}{dd}_note.{ext}", (812, Some(11), Some(2)), JournalExtension::Md => "08121102_note.md" ; "Should pad digits")]
+    #[test_case("journal {yyyy}.{ext}", (2023, None, None), JournalExtension::Txt => "journal 2023.txt" ; "Should create yearly name")]
+    fn should_format(
+        pattern: &str,
+        (y, m, d): (u32, Option<u32>, Option<u32>),
+        ext: JournalExtension,
+    ) -> String {
+        let pattern: NamingPattern = pattern.parse().unwrap();
+        pattern.format(&DateComponents::new(y, m, d), ext)
+    }
+
+    #[test_case("{yyyy}-{mm}-{dd}.md", "2023-03-09.md" => Some((2023, Some(3), Some(9))) ; "Should parse with literal extension")]
+    #[test_case("{yyyy}-{mm}-{dd}.{ext}", "2023-03-09.adoc" => Some((2023, Some(3), Some(9))) ; "Should parse with extension")]
+    #[test_case("{yyyy}{mm}{dd}.{ext}", "20230309.md" => Some((2023, Some(3), Some(9))) ; "Should parse without separators")]
+    #[test_case("{yyyy}-{mm}.{ext}", "2023-03.md" => Some((2023, Some(3), None)) ; "Should parse monthly")]
+    #[test_case("{yyyy}-{mm}-{dd}.{ext}", "2023-3-09.md" => None ; "Should not parse unpadded month")]
+    #[test_case("{yyyy}-{mm}-{dd}.{ext}", "2023-03-09.docx" => None ; "Should not parse unsupported extension")]
+    #[test_case("{yyyy}-{mm}-{dd}.md", "2023-03-09.md.bak" => None ; "Should not parse with trailing text")]
+    #[test_case("{yyyy}_{mm}_{dd}_daily.{ext}", "2023_03_09_daily.md" => Some((2023, Some(3), Some(9))) ; "Should parse default format")]
+    #[test_case("{yyyy}{mm}{dd}.{ext}", "100000309.md" => Some((10000, Some(3), Some(9))) ; "Should parse year with five digits without separators")]
+    #[test_case("{yyyy}-{mm}.{ext}", "10000-03.md" => Some((10000, Some(3), None)) ; "Should parse year with five digits")]
+    #[test_case("{yyyy}-{mm}.{ext}", "203-03.md" => None ; "Should not parse year with less than four digits")]
+    fn should_parse(pattern: &str, name: &str) -> Option<(u32, Option<u32>, Option<u32>)> {
+        let pattern: NamingPattern = pattern.parse().unwrap();
+        pattern
+            .parse(name)
+            .map(|parsed| (parsed.year(), parsed.month(), parsed.day()))
+    }
+
+    #[test_case("{mm}-{dd}.md" ; "Should fail without year")]
+    #[test_case("{yyyy}-{week}.md" ; "Should fail with unknown placeholder")]
+    #[test_case("{yyyy}/{mm}-{dd}.md" ; "Should fail with folder")]
+    fn should_reject_invalid_pattern(pattern: &str) {
+        assert!(pattern.parse::<NamingPattern>().is_err());
+    }
+
+    #[test_case("{yyyy}-{mm}-{dd}.md", true, true => true ; "Should accept daily")]
+    #[test_case("{yyyy}-{mm}.md", true, true => false ; "Should reject daily without day")]
+    #[test_case("{yyyy}-{mm}-{dd}.md", true, false => false ; "Should reject monthly with day")]
+    #[test_case("{yyyy}.md", false, false => true ; "Should accept yearly")]
+    fn should_ensure_placeholders(pattern: &str, with_month: bool, with_day: bool) -> bool {
+        let pattern: NamingPattern = pattern.parse().unwrap();
+        pattern.ensure_placeholders(with_month, with_day).is_ok()
+    }
+}
@@ -2,7 +2,10 @@ use chrono::NaiveDate;
 
 use date_validation_types::ValidatedDate;
 use crate::core::{date_models::open_by::OpenByDaysInTime, DailyName, constants::MD_EXT};
-use super::{daily_names::ParseDailyNameError, ToDateTuple, DateNameForFile};
+use super::{
+    daily_names::ParseDailyNameError, ToDateTuple, DateNameForFile, JournalExtension, NamingPattern,
+};
+use test_case::test_case;
 
 #[test]
 fn test_parse_error() {
@@ -14,6 +17,14 @@ fn test_parse_error() {
     assert_if_parse_fails_with("2000_2_32_daily.md", ParseDailyNameError::InvalidDate);
     assert_if_parse_fails_with("2000_0_1_daily.md", ParseDailyNameError::InvalidDate);
     assert_if_parse_fails_with("-78_2_2_daily.md", ParseDailyNameError::YearNotANumber);
+    assert_if_parse_fails_with(
+        "2000_02_01_daily.docx",
+        ParseDailyNameError::InvalidLabelOrExtension,
+    );
+    assert_if_parse_fails_with(
+        "2000_02_01_monthly.md",
+        ParseDailyNameError::InvalidLabelOrExtension,
+    );
 }
 
 #[test]
@@ -38,6 +49,17 @@ fn test_parse() {
         "2001_02_20_daily.md",
         NaiveDate::from_ymd_opt(2001, 2, 20).unwrap(),
     );
+    assert_parse(
+        "2001_02_20_daily.org",
+        NaiveDate::from_ymd_opt(2001, 2, 20).unwrap(),
+    );
+}
+
+#[test]
+fn should_keep_extension_of_parsed_name() {
+    let given = "2001_02_20_daily.org";
+    let actual: DailyName = given.parse().expect("Valid daily name given");
+    assert_eq!(given, actual.name());
 }
 
 #[test]
@@ -116,3 +138,17 @@ fn assert_parse(valid: &str, expected: NaiveDate) {
         panic!("Did parse valid input correctly, ({})", valid);
     }
 }
+
+#[test_case(2023, "{yyyy}{mm}{dd}.{ext}" => "20230309.org" ; "Should rename with pattern")]
+#[test_case(10000, "{yyyy}{mm}{dd}.{ext}" => "100000309.org" ; "Should rename year 10000 without separators")]
+#[test_case(10000, "{yyyy}-{mm}-{dd}.{ext}" => "10000-03-09.org" ; "Should rename year 10000")]
+fn should_rename_with_pattern(year: u32, pattern: &str) -> String {
+    let pattern: NamingPattern = pattern.parse().unwrap();
+    let given = DailyName::new(year, 3, 9, "md").unwrap();
+
+    given
+        .with_pattern(&pattern, JournalExtension::Org)
+        .unwrap()
+        .name()
+        .to_owned()
+}
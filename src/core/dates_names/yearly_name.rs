@@ -3,7 +3,9 @@ use super::*;
 use crate::{prelude::*, core::app_config::AppConfig};
 use std::str::FromStr;
 use thiserror::Error;
-use crate::core::constants::{DIGIT_SEP, MD_EXT, YEARLY_LABEL_IN_NAME};
+use crate::core::app_options::AppOptions;
+use crate::core::constants::{DIGIT_SEP, MD_EXT, YEARLY_LABEL_IN_NAME, YEAR_ROLLUP_VAR_NAME};
+use crate::core::rollup_actions;
 use date_validation_types::ValidatedYear;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -16,11 +18,36 @@ impl DateNameForFile for YearlyName {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn with_extension(&self, ext: JournalExtension) -> Self {
+        let name = Self::create_name(&self.year, ext.as_str());
+        Self {
+            year: self.year,
+            name,
+        }
+    }
+
+    fn choose_extension(&self, app_config: &AppConfig) -> Option<JournalExtension> {
+        app_config.yearly_extension()
+    }
+
+    fn date_components(&self) -> DateComponents {
+        DateComponents::new(self.year.into(), None, None)
+    }
+
+    fn configured_pattern(app_config: &AppConfig) -> Option<&NamingPattern> {
+        app_config.yearly_name_pattern().as_ref()
+    }
+
+    fn from_pattern(pattern: &NamingPattern, name: &str) -> Option<Self> {
+        let year: ValidatedYear = pattern.parse(name)?.year().try_into().ok()?;
+        Some(Self::with_name(year, name))
+    }
 }
 
 impl YearlyName {
     pub fn new(year: ValidatedYear) -> Self {
-        let name = Self::create_name(&year);
+        let name = Self::create_name(&year, MD_EXT);
         Self { year, name }
     }
     fn with_name(year: ValidatedYear, name: &str) -> Self {
@@ -28,11 +55,11 @@ impl YearlyName {
         Self { year, name }
     }
 
-    fn create_name(year: &ValidatedYear) -> String {
+    fn create_name(year: &ValidatedYear, ext: &str) -> String {
         let year: u32 = (*year).into();
         format!(
             "{0:04}{1}{2}.{3}",
-            year, DIGIT_SEP, YEARLY_LABEL_IN_NAME, MD_EXT
+            year, DIGIT_SEP, YEARLY_LABEL_IN_NAME, ext
         )
     }
 }
@@ -44,6 +71,17 @@ impl ResolvePlaceholders for YearlyName {
             _ => Cow::Borrowed(to_resolve),
         }
     }
+
+    fn resolve_from_journals(
+        &self,
+        to_resolve: &str,
+        option: &AppOptions,
+    ) -> AppResult<Option<String>> {
+        match to_resolve {
+            YEAR_ROLLUP_VAR_NAME => Ok(Some(rollup_actions::year_rollup(self, option)?)),
+            _ => Ok(None),
+        }
+    }
 }
 
 impl InitialabeFromTemplate for YearlyName {
@@ -83,8 +121,8 @@ impl FromStr for YearlyName {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let name = s;
         let mut splits = name.trim().split(DIGIT_SEP);
-        match (splits.next(), splits.next()) {
-            (Some(year), Some(maybe_digit)) => {
+        match (splits.next(), splits.next(), splits.next()) {
+            (Some(year), Some(maybe_digit), None) => {
                 let year: u32 = year
                     .parse()
                     .map_err(|_| ParseErrorForYearName::MissingYear)?;
@@ -97,6 +135,9 @@ impl FromStr for YearlyName {
                 if maybe_digit.parse::<u32>().is_ok() {
                     // if here then is text is a monthly or daily
                     Err(ParseErrorForYearName::InvalidFormat)
+                } else if JournalExtension::strip_label(maybe_digit, YEARLY_LABEL_IN_NAME).is_none()
+                {
+                    Err(ParseErrorForYearName::InvalidFormat)
                 } else {
                     Ok(Self::with_name(year, name))
                 }
@@ -147,6 +188,8 @@ mod testing {
     #[test_case("33")]
     #[test_case("33_10")]
     #[test_case("33_10_13")]
+    #[test_case("2000_yearly.docx")]
+    #[test_case("2000_weekly.md")]
     fn should_fail_parsing(input: &str) {
         let daily_name: Result<YearlyName, _> = input.parse();
         assert!(daily_name.is_err())
@@ -166,6 +209,7 @@ mod testing {
     #[test_case("188_yearly.md" => using validate_parsed_yearly_name(188))]
     #[test_case("1_yearly.md" => using validate_parsed_yearly_name(1))]
     #[test_case("24_yearly.md" => using validate_parsed_yearly_name(24))]
+    #[test_case("2001_yearly.adoc" => using validate_parsed_yearly_name(2001))]
     fn should_parse(name: &str) -> YearlyName {
         name.parse().expect("Should fail for valid input")
     }
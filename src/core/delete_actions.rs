@@ -1,29 +1,47 @@
-use std::fs;
+use std::path::PathBuf;
 use crate::cli::prompt;
 use crate::core::dates_names::DateNameForFile;
 use crate::prelude::*;
-use crate::core::file_access;
+use crate::core::{file_access, trash_actions};
 
 use crate::core::app_options::AppOptions;
 use crate::cli::deletion_arguments::*;
-use super::date_models::open_by::OpenByMonthInYear;
-use date_validation_types::{ValidatedDate, ValidatedYear};
+use super::date_models::{find_by::FindByRange, open_by::OpenByMonthInYear};
+use date_validation_types::{ValidatedDate, ValidatedMonth, ValidatedYear};
 use super::dates_names::yearly_name::YearlyName;
-use super::dates_names::{DailyName, MonthlyName};
+use super::dates_names::{DailyName, MonthlyName, HasYear};
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Debug)]
 pub enum DeletionResult {
     /// There was journal to be found for deletion
     NoJournalFound,
     /// Use did cancel the deletion when asked for confirmation
     NoConfirmation,
-    /// User confirmed and the journal was deleted
-    Deleted,
+    /// User confirmed and the journal was moved into the trash folder
+    Deleted {
+        /// Location of the deleted journal within the trash folder
+        trashed_at: PathBuf,
+    },
+    /// Deletion was only simulated. The journal at this location would have been deleted.
+    DryRun(PathBuf),
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum RangeDeletionResult {
+    /// There were no journals found within the range for deletion
+    NoJournalFound,
+    /// Use did cancel the deletion when asked for confirmation
+    NoConfirmation,
+    /// User confirmed and the journals were moved into the trash folder.
+    /// Contains the locations of the deleted journals within the trash folder.
+    Deleted(Vec<PathBuf>),
+    /// Deletion was only simulated. Contains names of the journals which would have been deleted.
+    DryRun(Vec<String>),
 }
 
 /// ## Errors
 /// - could not even determine if a journal exits or could delte the found journal for some reason
-/// for example permission
+///   for example permission
 pub fn delete_day_journal(
     to_delete: ValidatedDate,
     deletion_option: &CommonDeleteArg,
@@ -36,7 +54,7 @@ pub fn delete_day_journal(
 }
 /// ## Errors
 /// - could not even determine if a journal exits or could delte the found journal for some reason
-/// for example permission
+///   for example permission
 pub fn delete_month_journal(
     to_delete: OpenByMonthInYear,
     deletion_option: &CommonDeleteArg,
@@ -50,7 +68,7 @@ pub fn delete_month_journal(
 
 /// ## Errors
 /// - could not even determine if a journal exits or could delte the found journal for some reason
-/// for example permission
+///   for example permission
 pub fn delete_year_journal(
     to_delete: ValidatedYear,
     deletion_option: &CommonDeleteArg,
@@ -62,6 +80,114 @@ pub fn delete_year_journal(
     })
 }
 
+/// Deletes all daily journals within the given range after one confirmation.
+/// ## Errors
+/// - could not read the data folder or could not delete one of the found journals
+pub fn delete_day_journals_in_range(
+    range: &FindByRange<ValidatedDate>,
+    deletion_option: &CommonDeleteArg,
+    option: &AppOptions,
+) -> AppResult<RangeDeletionResult> {
+    let in_range: Vec<(DailyName, PathBuf)> =
+        file_access::fetch_valid_date_entries_with_paths(option)?
+            .into_iter()
+            .filter(|(daily, _): &(DailyName, PathBuf)| range.contains(&daily.date()))
+            .collect();
+
+    delete_given_journals(in_range, deletion_option, option, |names| {
+        ask_for_range_confirmation("daily", names)
+    })
+}
+
+/// Deletes all monthly journals within the given range after one confirmation.
+/// ## Errors
+/// - could not read the data folder or could not delete one of the found journals
+pub fn delete_month_journals_in_range(
+    range: &FindByRange<(ValidatedYear, ValidatedMonth)>,
+    deletion_option: &CommonDeleteArg,
+    option: &AppOptions,
+) -> AppResult<RangeDeletionResult> {
+    let in_range: Vec<(MonthlyName, PathBuf)> =
+        file_access::fetch_valid_date_entries_with_paths(option)?
+            .into_iter()
+            .filter(|(monthly, _): &(MonthlyName, PathBuf)| range.contains(&monthly.year_month()))
+            .collect();
+
+    delete_given_journals(in_range, deletion_option, option, |names| {
+        ask_for_range_confirmation("monthly", names)
+    })
+}
+
+/// Deletes all yearly journals within the given range after one confirmation.
+/// ## Errors
+/// - could not read the data folder or could not delete one of the found journals
+pub fn delete_year_journals_in_range(
+    range: FindByRange<ValidatedYear>,
+    deletion_option: &CommonDeleteArg,
+    option: &AppOptions,
+) -> AppResult<RangeDeletionResult> {
+    let range = range.map(u32::from);
+    let in_range: Vec<(YearlyName, PathBuf)> =
+        file_access::fetch_valid_date_entries_with_paths(option)?
+            .into_iter()
+            .filter(|(yearly, _): &(YearlyName, PathBuf)| range.contains(&yearly.year()))
+            .collect();
+
+    delete_given_journals(in_range, deletion_option, option, |names| {
+        ask_for_range_confirmation("yearly", names)
+    })
+}
+
+fn ask_for_range_confirmation(kind: &str, names: &[String]) -> AppResult<bool> {
+    let preview: Vec<String> = names.iter().map(|name| format!("- {name}")).collect();
+    prompt::ask_for_confirmation(&format!(
+        "The following {kind} journals will be deleted:\n{}\nyou want to delete these {} journals ?",
+        preview.join("\n"),
+        names.len()
+    ))
+}
+
+fn delete_given_journals<T>(
+    mut journals: Vec<(T, PathBuf)>,
+    deletion_option: &CommonDeleteArg,
+    option: &AppOptions,
+    on_confirmation: impl Fn(&[String]) -> AppResult<bool>,
+) -> AppResult<RangeDeletionResult>
+where
+    T: DateNameForFile,
+{
+    if journals.is_empty() {
+        return Ok(RangeDeletionResult::NoJournalFound);
+    }
+
+    journals.sort_by(|(left, _), (right, _)| left.cmp(right));
+    let names: Vec<String> = journals
+        .iter()
+        .map(|(journal, _)| journal.name().to_owned())
+        .collect();
+
+    if deletion_option.dry_run() {
+        return Ok(RangeDeletionResult::DryRun(names));
+    }
+
+    let wants_to_delete = if !deletion_option.skip_confirmation() {
+        on_confirmation(&names)
+    } else {
+        Ok(true)
+    }?;
+
+    if !wants_to_delete {
+        return Ok(RangeDeletionResult::NoConfirmation);
+    }
+
+    let mut trashed = Vec::with_capacity(names.len());
+    for (_, to_delete) in journals.iter() {
+        trashed.push(trash_actions::move_to_trash(to_delete, option)?);
+    }
+
+    Ok(RangeDeletionResult::Deleted(trashed))
+}
+
 fn delete_given_journal<T>(
     journal: T,
     deletion_option: &CommonDeleteArg,
@@ -71,34 +197,34 @@ fn delete_given_journal<T>(
 where
     T: DateNameForFile,
 {
-    let to_open = file_access::create_new_path_for(journal.name(), option)?;
-
-    match to_open.try_exists() {
-        Ok(does_exits) => {
-            if does_exits {
-                if to_open.is_file() {
-                    let wants_to_delete = if !deletion_option.skip_confirmation() {
-                        on_confirmation()
-                    } else {
-                        Ok(true)
-                    }?;
-
-                    if wants_to_delete {
-                        fs::remove_file(to_open)?;
-                        Ok(DeletionResult::Deleted)
-                    } else {
-                        Ok(DeletionResult::NoConfirmation)
-                    }
-                } else {
-                    bail!("Critical: found directory where file as journal was expected to be deleted")
-                }
-            } else {
-                // no journal found to delete
-                Ok(DeletionResult::NoJournalFound)
-            }
-        }
-        Err(error) => Err(AppError::new(error).context(
-            "Could not delete chosen journal. Could not event determine if a journal exits.",
-        )),
+    let found = file_access::find_existing_journal(&journal, option).context(
+        "Could not delete chosen journal. Could not event determine if a journal exits.",
+    )?;
+
+    let to_open = match found {
+        Some(existing) => existing,
+        // no journal found to delete
+        None => return Ok(DeletionResult::NoJournalFound),
+    };
+
+    if !to_open.is_file() {
+        bail!("Critical: found directory where file as journal was expected to be deleted")
+    }
+
+    if deletion_option.dry_run() {
+        return Ok(DeletionResult::DryRun(to_open));
+    }
+
+    let wants_to_delete = if !deletion_option.skip_confirmation() {
+        on_confirmation()
+    } else {
+        Ok(true)
+    }?;
+
+    if wants_to_delete {
+        let trashed_at = trash_actions::move_to_trash(&to_open, option)?;
+        Ok(DeletionResult::Deleted { trashed_at })
+    } else {
+        Ok(DeletionResult::NoConfirmation)
     }
 }
@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use date_validation_types::ValidatedYear;
+
+use crate::core::app_config::AppConfig;
+use crate::core::app_options::AppOptions;
+use crate::core::constants::{CONF_FILE_NAME, DAILY_INFIX, MONTHLY_LABEL_IN_NAME, YEARLY_LABEL_IN_NAME};
+use crate::core::{file_access, trash_actions};
+use crate::prelude::*;
+
+use super::dates_names::{
+    yearly_name::YearlyName, DailyName, DateNameForFile, JournalExtension, MonthlyName,
+};
+
+/// Characters which are accepted as separator between the parts of a near miss name.
+const LOOSE_SEPARATORS: [char; 3] = ['_', '-', ' '];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HealthIssue {
+    /// File which is not recognized as any kind of journal.
+    Unrecognized(PathBuf),
+    /// File which is almost named like a journal. Example: 2023_3_9_daily.md
+    /// The suggested name is in the same folder.
+    NearMiss { path: PathBuf, suggested: PathBuf },
+    /// File named like a journal whose date does not exist. Example: 2023_02_30_daily.md
+    InvalidDate(PathBuf),
+    /// Several files for the same journal. They differ by extension or folder.
+    /// The file to keep is the most recently modified one.
+    Duplicates { keep: PathBuf, others: Vec<PathBuf> },
+    /// Journal without any content.
+    Empty(PathBuf),
+    /// File or folder which could not be read at all.
+    Unreadable { path: PathBuf, reason: String },
+}
+
+impl HealthIssue {
+    /// Returns a human readable description of the problem.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Unrecognized(path) => format!("{:?} is not recognized as a journal", path),
+            Self::NearMiss { path, suggested } => format!(
+                "{:?} is almost named like a journal. Expected name: {:?}",
+                path, suggested
+            ),
+            Self::InvalidDate(path) => {
+                format!("{:?} is named after a date which does not exist", path)
+            }
+            Self::Duplicates { keep, others } => {
+                let mut all: Vec<&PathBuf> = others.iter().collect();
+                all.push(keep);
+                format!("There are several files for the same journal: {:?}", all)
+            }
+            Self::Empty(path) => format!("{:?} is an empty journal", path),
+            Self::Unreadable { path, reason } => {
+                format!("{:?} could not be read. Cause: {}", path, reason)
+            }
+        }
+    }
+
+    /// Returns what the fix of this problem does. Returns none if there is no fix.
+    pub fn describe_fix(&self) -> Option<String> {
+        match self {
+            Self::Unrecognized(path) | Self::InvalidDate(path) | Self::Empty(path) => {
+                Some(format!("Move {:?} to the trash", path))
+            }
+            Self::NearMiss { path, suggested } => {
+                Some(format!("Rename {:?} to {:?}", path, suggested))
+            }
+            Self::Duplicates { keep, others } => Some(format!(
+                "Keep {:?} and move {:?} to the trash",
+                keep, others
+            )),
+            Self::Unreadable { .. } => None,
+        }
+    }
+
+    /// Fixes the problem. Files are never removed but moved to the trash.
+    /// ## Errors
+    /// - There is no fix for this problem.
+    /// - The rename target of a near miss exists already.
+    /// - A file could not be moved.
+    pub fn fix(&self, option: &AppOptions) -> AppResult {
+        match self {
+            Self::Unrecognized(path) | Self::InvalidDate(path) | Self::Empty(path) => {
+                trash_actions::move_to_trash(path, option)?;
+            }
+            Self::NearMiss { path, suggested } => {
+                if suggested.try_exists()? {
+                    bail!(
+                        "Could not rename {:?}. There is already a file at {:?}",
+                        path,
+                        suggested
+                    );
+                }
+                if let Some(folder) = suggested.parent() {
+                    fs::create_dir_all(folder)?;
+                }
+                fs::rename(path, suggested)
+                    .with_context(|| format!("Could not rename {:?}", path))?;
+            }
+            Self::Duplicates { others, .. } => {
+                for other in others {
+                    trash_actions::move_to_trash(other, option)?;
+                }
+            }
+            Self::Unreadable { path, .. } => {
+                bail!("There is no fix for unreadable entry {:?}", path)
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks every entry of the data folder and returns all found problems.
+/// The config file and template files inside the data folder are not reported.
+pub fn diagnose(option: &AppOptions) -> AppResult<Vec<HealthIssue>> {
+    let scanned = file_access::scan_data_folder(option)?;
+    let config = option.load_config()?;
+    let app_files = fetch_app_files(config);
+
+    let mut issues: Vec<HealthIssue> = scanned
+        .unreadable()
+        .iter()
+        .map(|unreadable| HealthIssue::Unreadable {
+            path: unreadable.path().clone(),
+            reason: unreadable.reason().clone(),
+        })
+        .collect();
+    let mut same_journals: BTreeMap<(&str, String), Vec<PathBuf>> = BTreeMap::new();
+
+    for path in scanned.files() {
+        if app_files.contains(path) {
+            continue;
+        }
+
+        let file_name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let ext: JournalExtension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse().ok())
+            .unwrap_or_default();
+
+        match recognize_kind(file_name, ext, config) {
+            Some((key, expected)) => {
+                if !expected.iter().any(|name| name == file_name) {
+                    issues.push(HealthIssue::NearMiss {
+                        path: path.clone(),
+                        suggested: path.with_file_name(&expected[0]),
+                    });
+                }
+                if let Some(issue) = diagnose_content(path) {
+                    issues.push(issue);
+                }
+                same_journals.entry(key).or_default().push(path.clone());
+            }
+            None => issues.push(diagnose_unrecognized(path, file_name, config)),
+        }
+    }
+
+    for (_, mut paths) in same_journals {
+        if paths.len() > 1 {
+            paths.sort_by_key(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok());
+            let keep = paths.pop().expect("There are at least 2 paths");
+            issues.push(HealthIssue::Duplicates {
+                keep,
+                others: paths,
+            });
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Reports a journal without content or which could not be read.
+fn diagnose_content(path: &Path) -> Option<HealthIssue> {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.len() == 0 => Some(HealthIssue::Empty(path.to_path_buf())),
+        Ok(_) => None,
+        Err(error) => Some(HealthIssue::Unreadable {
+            path: path.to_path_buf(),
+            reason: error.to_string(),
+        }),
+    }
+}
+
+fn fetch_app_files(config: Option<&AppConfig>) -> Vec<PathBuf> {
+    match config {
+        Some(loaded) => [
+            loaded.daily_template(),
+            loaded.monthly_template(),
+            loaded.yearly_template(),
+        ]
+        .iter()
+        .filter_map(|template| template.try_to_resolved_path(loaded))
+        .chain(std::iter::once(loaded.root_path().join(CONF_FILE_NAME)))
+        .chain(loaded.layer_files().iter().cloned())
+        .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Kind of journal with its date. Journals with the same key are duplicates.
+type JournalKey = (&'static str, String);
+
+/// Returns the key of the journal and its expected names if the file name is recognized.
+fn recognize_kind(
+    file_name: &str,
+    ext: JournalExtension,
+    config: Option<&AppConfig>,
+) -> Option<(JournalKey, Vec<String>)> {
+    recognize::<DailyName>(file_name, ext, config, DAILY_INFIX)
+        .or_else(|| recognize::<MonthlyName>(file_name, ext, config, MONTHLY_LABEL_IN_NAME))
+        .or_else(|| recognize::<YearlyName>(file_name, ext, config, YEARLY_LABEL_IN_NAME))
+}
+
+fn recognize<T>(
+    file_name: &str,
+    ext: JournalExtension,
+    config: Option<&AppConfig>,
+    kind: &'static str,
+) -> Option<(JournalKey, Vec<String>)>
+where
+    T: DateNameForFile,
+{
+    let journal: T = file_access::parse_journal_name(file_name, config)?;
+    Some((
+        (kind, journal.to_date_tuple()),
+        expected_names(&journal, ext, config),
+    ))
+}
+
+/// Returns the names a journal may have. These are the name after the configured naming pattern
+/// and the name in the default format. The preferred name comes first.
+fn expected_names<T>(journal: &T, ext: JournalExtension, config: Option<&AppConfig>) -> Vec<String>
+where
+    T: DateNameForFile,
+{
+    let mut names = Vec::with_capacity(2);
+    if let Some(Ok(renamed)) = config.and_then(|config| {
+        T::configured_pattern(config).map(|pattern| journal.with_pattern(pattern, ext))
+    }) {
+        names.push(renamed.name().to_owned());
+    }
+    names.push(journal.with_extension(ext).name().to_owned());
+
+    names
+}
+
+fn diagnose_unrecognized(path: &Path, file_name: &str, config: Option<&AppConfig>) -> HealthIssue {
+    let (label, numbers, ext) = match loose_parse(file_name) {
+        Some(parsed) => parsed,
+        None => return HealthIssue::Unrecognized(path.to_path_buf()),
+    };
+
+    let expected = match (label.as_str(), numbers.as_slice()) {
+        (DAILY_INFIX, [year, month, day]) => DailyName::new(*year, *month, *day, ext.as_str())
+            .ok()
+            .map(|daily| expected_names(&daily, ext, config)),
+        (MONTHLY_LABEL_IN_NAME, [year, month]) => MonthlyName::from_ym(*year, *month, ext.as_str())
+            .ok()
+            .map(|monthly| expected_names(&monthly, ext, config)),
+        (YEARLY_LABEL_IN_NAME, [year]) => ValidatedYear::try_from(*year)
+            .ok()
+            .map(|valid| expected_names(&YearlyName::new(valid), ext, config)),
+        _ => return HealthIssue::Unrecognized(path.to_path_buf()),
+    };
+
+    match expected {
+        Some(names) => HealthIssue::NearMiss {
+            path: path.to_path_buf(),
+            suggested: path.with_file_name(&names[0]),
+        },
+        None => HealthIssue::InvalidDate(path.to_path_buf()),
+    }
+}
+
+/// Splits a name like "2023-3-9 Daily.MD" into its label, numbers and extension.
+/// Returns none if the name does not end with a label and an extension or
+/// if there are other parts than numbers before the label.
+fn loose_parse(file_name: &str) -> Option<(String, Vec<u32>, JournalExtension)> {
+    let lowered = file_name.trim().to_lowercase();
+    let (stem, ext) = lowered.rsplit_once('.')?;
+    let ext: JournalExtension = ext.parse().ok()?;
+
+    let mut parts: Vec<&str> = stem
+        .split(LOOSE_SEPARATORS)
+        .filter(|part| !part.is_empty())
+        .collect();
+    let label = parts.pop()?.to_owned();
+    let numbers = parts
+        .into_iter()
+        .map(|part| part.parse().ok())
+        .collect::<Option<Vec<u32>>>()?;
+
+    Some((label, numbers, ext))
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn should_report_vanished_file_as_unreadable() {
+        let path = PathBuf::from("/does/not/exist/2023_03_09_daily.md");
+
+        let actual = diagnose_content(&path);
+
+        assert!(
+            matches!(actual, Some(HealthIssue::Unreadable { path: reported, .. }) if reported == path)
+        );
+    }
+
+    #[test_case("2023_3_9_daily.md" => Some(("daily".to_owned(), vec![2023, 3, 9], JournalExtension::Md)) ; "Should parse unpadded daily")]
+    #[test_case("2023-03 Monthly.ORG" => Some(("monthly".to_owned(), vec![2023, 3], JournalExtension::Org)) ; "Should parse other separators and case")]
+    #[test_case("2023_3_9_daily.docx" => None ; "Should not parse unsupported extension")]
+    #[test_case("notes_daily.md" => None ; "Should not parse words before label")]
+    #[test_case("daily" => None ; "Should not parse without extension")]
+    fn should_loose_parse(file_name: &str) -> Option<(String, Vec<u32>, JournalExtension)> {
+        loose_parse(file_name)
+    }
+}
@@ -1,12 +1,17 @@
 use std::{
-    str::FromStr,
+    cmp::Ordering,
+    collections::BTreeMap,
     path::{Path, PathBuf},
     fs::{self, DirEntry},
 };
+use crate::core::app_config::AppConfig;
 use crate::core::app_options::AppOptions;
+use crate::core::constants::TRASH_FOLDER;
+use crate::core::dates_names::{DateNameForFile, JournalExtension};
+use derive_new::new;
 use dirs;
 use crate::prelude::*;
-use super::date_filtering;
+use super::{data_layout, date_filtering};
 
 pub fn resolve_str_as_path(to_resolve: &str) -> PathBuf {
     let expanded_path = shellexpand::full_with_context_no_errors(
@@ -53,57 +58,389 @@ pub fn fetch_path_conf(option: &AppOptions) -> AppResult<PathBuf> {
     Ok(conf_dir)
 }
 
+/// Returns all journals in the data folder whose file name is recognized.
+/// A file name is recognized if it follows the configured naming pattern or the default format.
 pub fn fetch_valid_date_entries<R>(option: &AppOptions) -> AppResult<Vec<R>>
 where
-    R: FromStr,
+    R: DateNameForFile,
 {
     let file_names = fetch_file_names_from_dates(option)?;
+    let config = option.load_config()?;
 
     let filtered = file_names
         .into_iter()
-        .filter_map(|file_name| file_name.parse().ok())
+        .filter_map(|file_name| parse_journal_name(&file_name, config))
         .collect();
 
     Ok(filtered)
 }
-pub fn create_new_path_for(file_name: &str, option: &AppOptions) -> AppResult<PathBuf> {
+
+/// Returns the same as [`fetch_valid_date_entries`] together with the path of every entry.
+/// Needed for journals which reside in sub folders of the data folder.
+pub fn fetch_valid_date_entries_with_paths<R>(option: &AppOptions) -> AppResult<Vec<(R, PathBuf)>>
+where
+    R: DateNameForFile,
+{
+    let journal_paths = get_all_journal_paths(option)?;
+    let config = option.load_config()?;
+
+    let filtered = journal_paths
+        .into_iter()
+        .filter_map(|path| {
+            let parsed = parse_journal_name(path.file_name()?.to_str()?, config)?;
+            Some((parsed, path))
+        })
+        .collect();
+
+    Ok(filtered)
+}
+
+/// Returns the journal for the given file name if it follows the configured naming pattern
+/// or the default format.
+pub fn parse_journal_name<R>(file_name: &str, config: Option<&AppConfig>) -> Option<R>
+where
+    R: DateNameForFile,
+{
+    config
+        .and_then(R::configured_pattern)
+        .and_then(|pattern| R::from_pattern(pattern, file_name))
+        .or_else(|| file_name.parse().ok())
+}
+
+/// Returns the path for the given journal according to the configured layout of the data folder.
+/// Folders of the layout are created if they do not exist yet.
+pub fn create_new_path_for<T>(journal: &T, option: &AppOptions) -> AppResult<PathBuf>
+where
+    T: DateNameForFile,
+{
+    let new_path = path_in_layout(journal, option)?;
+
+    if let Some(folder) = new_path.parent() {
+        fs::create_dir_all(folder)
+            .with_context(|| format!("Could not create folder for journal at {:?}", folder))?;
+    }
+
+    Ok(new_path)
+}
+
+/// Returns the path to the trash folder inside the data folder.
+/// The trash folder may not exist yet.
+pub fn fetch_trash_path(option: &AppOptions) -> AppResult<PathBuf> {
+    Ok(option.get_data_path()?.join(TRASH_FOLDER))
+}
+
+/// Returns the path of the given journal in the data folder.
+/// An already existing journal for the same date is found regardless of its extension and folder.
+/// Otherwise the path ends with the extension configured for this kind of journal
+/// and is placed according to the configured layout.
+pub fn resolve_journal_path<T>(journal: &T, option: &AppOptions) -> AppResult<PathBuf>
+where
+    T: DateNameForFile,
+{
+    match find_existing_journal(journal, option)? {
+        Some(existing) => Ok(existing),
+        None => create_new_path_for(&with_configured_name(journal, option)?, option),
+    }
+}
+
+/// Returns the same path as [`resolve_journal_path`] without creating any folder for a new journal.
+pub fn peek_journal_path<T>(journal: &T, option: &AppOptions) -> AppResult<PathBuf>
+where
+    T: DateNameForFile,
+{
+    match find_existing_journal(journal, option)? {
+        Some(existing) => Ok(existing),
+        None => path_in_layout(&with_configured_name(journal, option)?, option),
+    }
+}
+
+/// Returns the path of an existing journal for the same date as the given journal.
+/// The path according to the configured layout and extension is tried first.
+/// After that all journals within the data folder are searched.
+pub fn find_existing_journal<T>(journal: &T, option: &AppOptions) -> AppResult<Option<PathBuf>>
+where
+    T: DateNameForFile,
+{
+    let configured_path = path_in_layout(&with_configured_name(journal, option)?, option)?;
+    if configured_path.try_exists()? {
+        return Ok(Some(configured_path));
+    }
+
+    if !option.get_data_path()?.is_dir() {
+        return Ok(None);
+    }
+
+    let found = fetch_valid_date_entries_with_paths::<T>(option)?
+        .into_iter()
+        .find(|(entry, _)| entry.cmp(journal) == Ordering::Equal)
+        .map(|(_, path)| path);
+
+    if let Some(other_path) = found.as_ref() {
+        debug!(
+            "Found existing journal with other extension or folder at {:?}",
+            other_path
+        );
+    }
+
+    Ok(found)
+}
+
+/// Returns the path which the given journal with the given extension should have
+/// according to the configured naming pattern and layout.
+/// Unlike [`create_new_path_for`] no folders are created.
+pub fn configured_path_for<T>(
+    journal: &T,
+    ext: JournalExtension,
+    option: &AppOptions,
+) -> AppResult<PathBuf>
+where
+    T: DateNameForFile,
+{
+    let config = option.load_config()?;
+    path_in_layout(&rename_by_config(journal, ext, config)?, option)
+}
+
+/// Returns the given journal with the configured naming pattern and extension of its kind.
+fn with_configured_name<T>(journal: &T, option: &AppOptions) -> AppResult<T>
+where
+    T: DateNameForFile,
+{
+    let config = option.load_config()?;
+    let configured: JournalExtension = config
+        .and_then(|config| journal.choose_extension(config))
+        .unwrap_or_default();
+
+    rename_by_config(journal, configured, config)
+}
+
+fn rename_by_config<T>(
+    journal: &T,
+    ext: JournalExtension,
+    config: Option<&AppConfig>,
+) -> AppResult<T>
+where
+    T: DateNameForFile,
+{
+    match config.and_then(T::configured_pattern) {
+        Some(pattern) => journal.with_pattern(pattern, ext),
+        None => Ok(journal.with_extension(ext)),
+    }
+}
+
+fn path_in_layout<T>(journal: &T, option: &AppOptions) -> AppResult<PathBuf>
+where
+    T: DateNameForFile,
+{
     let data_folder_root = option.get_data_path()?;
 
-    Ok(data_folder_root.join(file_name))
+    let folder = match option
+        .load_config()?
+        .and_then(|config| config.data_layout().as_deref())
+    {
+        Some(layout) => {
+            data_folder_root.join(data_layout::folder_for(layout, &journal.date_components()))
+        }
+        None => data_folder_root,
+    };
+
+    Ok(folder.join(journal.name()))
 }
 
+/// Returns the paths of all files within the data folder and its sub folders.
+/// Hidden folders like the trash folder are skipped.
 pub fn get_all_journal_paths(option: &AppOptions) -> AppResult<Vec<PathBuf>> {
+    let scanned = scan_data_folder(option)?;
+
+    for unreadable in scanned.unreadable() {
+        warn!(
+            "Entry {0:?} could not be read\n. Cause: {1}",
+            unreadable.path(),
+            unreadable.reason()
+        );
+    }
+
+    Ok(scanned.files)
+}
+
+#[derive(Debug, Getters, new)]
+#[getset(get = "pub")]
+/// Entry within the data folder which could not be read.
+pub struct UnreadableEntry {
+    path: PathBuf,
+    reason: String,
+}
+
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+/// Result of walking through the data folder and its sub folders.
+pub struct DataFolderScan {
+    /// Every file found.
+    files: Vec<PathBuf>,
+    /// Entries which could not be read or whose file names are not valid utf8.
+    unreadable: Vec<UnreadableEntry>,
+}
+
+/// Returns all files within the data folder and its sub folders together with entries
+/// which could not be read. Hidden folders like the trash folder are skipped.
+pub fn scan_data_folder(option: &AppOptions) -> AppResult<DataFolderScan> {
     let data_folder = option
         .get_data_path()
         .context("Failed to get path to data/journals")?;
 
-    let gathered_dailies = fs::read_dir(&data_folder)
+    scan_folder(data_folder)
+}
+
+/// Returns the journals of the data folder and the archive folders sorted from the oldest to the
+/// newest one. A journal of the data folder takes precedence over an archived one of the same date.
+/// Archive folders within the data folder do not count as part of the data folder.
+pub fn fetch_entries_with_archive<R>(option: &AppOptions) -> AppResult<Vec<(R, PathBuf)>>
+where
+    R: DateNameForFile,
+{
+    let archive_folders = option
+        .load_config()?
+        .map(|config| config.archive_folders())
+        .unwrap_or_default();
+    let active = fetch_valid_date_entries_with_paths::<R>(option)?
+        .into_iter()
+        .filter(|(_, path)| !is_in_archive_folder(path, &archive_folders));
+    let archived = fetch_archived_entries_with_paths::<R>(option)?;
+
+    let mut found: BTreeMap<R, PathBuf> = BTreeMap::new();
+    for (journal, path) in active.chain(archived) {
+        found.entry(journal).or_insert(path);
+    }
+
+    Ok(found.into_iter().collect())
+}
+
+/// Returns true if the given path lies within one of the given archive folders.
+pub fn is_in_archive_folder(path: &Path, archive_folders: &[PathBuf]) -> bool {
+    archive_folders.iter().any(|folder| path.starts_with(folder))
+}
+
+/// Returns all journals within the archive folders of the config whose file name is recognized.
+/// Archive folders which do not exist are skipped with a warning.
+pub fn fetch_archived_entries_with_paths<R>(option: &AppOptions) -> AppResult<Vec<(R, PathBuf)>>
+where
+    R: DateNameForFile,
+{
+    let config = match option.load_config()? {
+        Some(loaded) => loaded,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut archived = Vec::new();
+    for archive_folder in config.archive_folders() {
+        if !archive_folder.is_dir() {
+            warn!("Archive folder {:?} does not exist", &archive_folder);
+            continue;
+        }
+
+        let scanned = scan_folder(archive_folder)?;
+        for unreadable in scanned.unreadable() {
+            warn!(
+                "Entry {0:?} could not be read\n. Cause: {1}",
+                unreadable.path(),
+                unreadable.reason()
+            );
+        }
+        archived.extend(scanned.files.into_iter().filter_map(|path| {
+            let parsed = parse_journal_name(path.file_name()?.to_str()?, Some(config))?;
+            Some((parsed, path))
+        }));
+    }
+
+    Ok(archived)
+}
+
+/// Returns the path of a journal for the same date as the given journal within the
+/// archive folders. Archive folders are searched in the configured order.
+pub fn find_archived_journal<T>(journal: &T, option: &AppOptions) -> AppResult<Option<PathBuf>>
+where
+    T: DateNameForFile,
+{
+    let found = fetch_archived_entries_with_paths::<T>(option)?
+        .into_iter()
+        .find(|(entry, _)| entry.cmp(journal) == Ordering::Equal)
+        .map(|(_, path)| path);
+
+    Ok(found)
+}
+
+/// Copies the given archived journal into the data folder and returns its new path.
+/// The copy keeps its extension but is named and placed like a new journal.
+pub fn restore_archived_journal<T>(
+    journal: &T,
+    archived: &Path,
+    option: &AppOptions,
+) -> AppResult<PathBuf>
+where
+    T: DateNameForFile,
+{
+    let ext: JournalExtension = archived
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| ext.parse().ok())
+        .unwrap_or_default();
+    let target = configured_path_for(journal, ext, option)?;
+    if let Some(folder) = target.parent() {
+        fs::create_dir_all(folder)
+            .with_context(|| format!("Could not create folder for journal at {:?}", folder))?;
+    }
+    fs::copy(archived, &target).with_context(|| {
+        format!(
+            "Could not copy archived journal {:?} to {:?}",
+            archived, &target
+        )
+    })?;
+
+    Ok(target)
+}
+
+fn scan_folder(data_folder: PathBuf) -> AppResult<DataFolderScan> {
+    let root_entries = fs::read_dir(&data_folder)
         .map_err(AppError::new)
         .with_context(|| {
             format!(
                 "At path {:?} there is no directory which can contain journals to find",
                 &data_folder
             )
-        })?
-        .filter_map(|entry| match entry {
-            Ok(resolved) => {
-                if is_file(&resolved) {
-                    Some(resolved.path())
-                } else {
-                    None
+        })?;
+
+    let mut files = Vec::new();
+    let mut unreadable = Vec::new();
+    let mut to_visit = vec![(data_folder, root_entries)];
+
+    while let Some((folder, entries)) = to_visit.pop() {
+        for entry in entries {
+            match entry {
+                Ok(resolved) => {
+                    if resolved.file_name().to_str().is_none() {
+                        unreadable.push(UnreadableEntry::new(
+                            resolved.path(),
+                            "Name is not valid utf8".to_owned(),
+                        ));
+                    } else if is_file(&resolved) {
+                        files.push(resolved.path());
+                    } else if is_visible_dir(&resolved) {
+                        let sub_folder = resolved.path();
+                        match fs::read_dir(&sub_folder) {
+                            Ok(sub_entries) => to_visit.push((sub_folder, sub_entries)),
+                            Err(error) => {
+                                unreadable.push(UnreadableEntry::new(sub_folder, error.to_string()))
+                            }
+                        }
+                    }
+                }
+                Err(error) => {
+                    unreadable.push(UnreadableEntry::new(folder.clone(), error.to_string()))
                 }
             }
-            Err(error) => {
-                warn!(
-                    "Entry could not be read in directory {0:?}\n. Cause: {error}",
-                    &data_folder
-                );
-                None
-            }
-        })
-        .collect();
+        }
+    }
 
-    return Ok(gathered_dailies);
+    return Ok(DataFolderScan { files, unreadable });
 
     fn is_file(to_check: &DirEntry) -> bool {
         if let Ok(file_type) = to_check.file_type() {
@@ -112,6 +449,14 @@ pub fn get_all_journal_paths(option: &AppOptions) -> AppResult<Vec<PathBuf>> {
             false
         }
     }
+
+    fn is_visible_dir(to_check: &DirEntry) -> bool {
+        let is_dir = to_check
+            .file_type()
+            .map(|file_type| file_type.is_dir())
+            .unwrap_or(false);
+        is_dir && !to_check.file_name().to_string_lossy().starts_with('.')
+    }
 }
 
 fn fetch_file_names_from_dates(option: &AppOptions) -> AppResult<Vec<String>> {
@@ -0,0 +1,226 @@
+//! Every read and write of journal content goes through this module.
+//! If encryption is configured then journals are stored encrypted at rest and
+//! only decrypted into a private temporary file while they are opened by the editor.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use age::secrecy::SecretString;
+use age::{scrypt, x25519};
+
+use crate::core::app_config::AppConfig;
+use crate::core::app_options::AppOptions;
+use crate::core::constants::ENCRYPTED_JOURNAL_MAGIC;
+use crate::core::process_handling::ProcessExecuter;
+use crate::prelude::*;
+
+enum JournalCipher {
+    Passphrase(SecretString),
+    Identity(Box<x25519::Identity>),
+}
+
+impl JournalCipher {
+    fn encrypt(&self, content: &str) -> AppResult<Vec<u8>> {
+        let encrypted = match self {
+            Self::Passphrase(passphrase) => age::encrypt(
+                &scrypt::Recipient::new(passphrase.clone()),
+                content.as_bytes(),
+            ),
+            Self::Identity(identity) => age::encrypt(&identity.to_public(), content.as_bytes()),
+        }?;
+
+        Ok(encrypted)
+    }
+
+    fn decrypt(&self, encrypted: &[u8]) -> AppResult<String> {
+        let decrypted = match self {
+            Self::Passphrase(passphrase) => {
+                age::decrypt(&scrypt::Identity::new(passphrase.clone()), encrypted)
+            }
+            Self::Identity(identity) => age::decrypt(identity.as_ref(), encrypted),
+        }?;
+
+        String::from_utf8(decrypted).context("Decrypted journal is not valid utf8")
+    }
+}
+
+/// Returns the content of the journal at `path`. Encrypted journals are decrypted.
+/// Returns none if there is no journal at `path`.
+pub fn read_journal(path: &Path, option: &AppOptions) -> AppResult<Option<String>> {
+    let raw = match fs::read(path) {
+        Ok(raw) => raw,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(AppError::new(error)),
+    };
+
+    if !raw.starts_with(ENCRYPTED_JOURNAL_MAGIC) {
+        let content = String::from_utf8(raw)
+            .with_context(|| format!("Journal at {:?} is not valid utf8", path))?;
+        return Ok(Some(content));
+    }
+
+    let cipher = load_cipher(option)?.ok_or_else(|| {
+        anyhow!(
+            "Journal at {:?} is encrypted but no encryption is configured",
+            path
+        )
+    })?;
+
+    let content = cipher
+        .decrypt(&raw)
+        .with_context(|| format!("Could not decrypt journal at {:?}", path))?;
+
+    Ok(Some(content))
+}
+
+/// Writes `content` as journal to `path`. It is encrypted if encryption is configured.
+pub fn write_journal(path: &Path, content: &str, option: &AppOptions) -> AppResult {
+    match load_cipher(option)? {
+        Some(cipher) => write_with_cipher(&cipher, path, content),
+        None => fs::write(path, content).map_err(AppError::new),
+    }
+}
+
+/// Opens the journals at `paths` together in one session of the given editor.
+/// If encryption is configured then the editor only sees decrypted copies in a private temporary
+/// folder. The copies are encrypted back to `paths` after the editor is closed successfully.
+/// The temporary folder is removed afterwards.
+/// ## Errors
+/// - Editor could not be started or did not exit successfully. Nothing is encrypted back then.
+/// - An edited copy could not be encrypted back. The other copies are still encrypted back.
+///
+/// In both cases the temporary folder is kept so no edit is lost. Its location is part of the error.
+pub fn edit_journals(
+    process_executer: &impl ProcessExecuter,
+    editor: &str,
+    paths: &[&Path],
+    option: &AppOptions,
+) -> AppResult {
+    let cipher = match load_cipher(option)? {
+        Some(cipher) => cipher,
+        None => return process_executer.start_program(option, editor, paths),
+    };
+
+    // Dropping the temporary folder removes the decrypted copies with it.
+    let private_folder = tempfile::Builder::new()
+        .prefix(env!("CARGO_PKG_NAME"))
+        .tempdir()
+        .context("Could not create private temporary folder for decrypted journal")?;
+
+    let mut decrypted_paths = Vec::with_capacity(paths.len());
+    for (index, path) in paths.iter().enumerate() {
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| anyhow!("Journal at {:?} has no file name", path))?;
+        // Journals in different folders may have the same file name.
+        let decrypted_folder = private_folder.path().join(index.to_string());
+        fs::create_dir(&decrypted_folder)?;
+        let decrypted_path = decrypted_folder.join(file_name);
+
+        if let Some(content) = read_journal(path, option)? {
+            fs::write(&decrypted_path, content)?;
+        }
+        decrypted_paths.push(decrypted_path);
+    }
+
+    let to_open: Vec<&Path> = decrypted_paths.iter().map(PathBuf::as_path).collect();
+    if let Err(error) = process_executer.start_program(option, editor, &to_open) {
+        // The copies may only contain a partial edit of a crashed editor.
+        let kept_folder = private_folder.into_path();
+        return Err(error.context(format!(
+            "Journals were not encrypted back. Their decrypted copies are kept at {:?}",
+            kept_folder
+        )));
+    }
+
+    let mut not_encrypted = Vec::new();
+    for (path, decrypted_path) in paths.iter().zip(&decrypted_paths) {
+        let encrypted_back = match fs::read_to_string(decrypted_path) {
+            Ok(edited) => write_with_cipher(&cipher, path, &edited),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                info!(
+                    "Editor did not save journal {:?}. Nothing to encrypt.",
+                    path
+                );
+                Ok(())
+            }
+            Err(error) => Err(AppError::new(error)),
+        };
+
+        if let Err(error) = encrypted_back {
+            warn!(
+                "Could not encrypt journal {:?} back.
+{:?}",
+                path, error
+            );
+            not_encrypted.push(path);
+        }
+    }
+
+    if !not_encrypted.is_empty() {
+        let kept_folder = private_folder.into_path();
+        bail!(
+            "Could not encrypt the edited journals {:?} back. Their decrypted copies are kept at {:?}",
+            not_encrypted,
+            kept_folder
+        );
+    }
+
+    private_folder
+        .close()
+        .context("Could not remove private temporary folder of decrypted journal")?;
+
+    Ok(())
+}
+
+fn write_with_cipher(cipher: &JournalCipher, path: &Path, content: &str) -> AppResult {
+    let encrypted = cipher.encrypt(content)?;
+    fs::write(path, encrypted)
+        .with_context(|| format!("Could not write encrypted journal to {:?}", path))
+}
+
+fn load_cipher(option: &AppOptions) -> AppResult<Option<JournalCipher>> {
+    let config = match option.load_config()? {
+        Some(config) => config,
+        None => return Ok(None),
+    };
+
+    match config.encryption() {
+        Some(_) => create_cipher(config).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn create_cipher(config: &AppConfig) -> AppResult<JournalCipher> {
+    let encryption = config
+        .encryption()
+        .as_ref()
+        .expect("Unexpected: create_cipher is only called with configured encryption");
+
+    if let Some(key_file) = encryption.key_file().try_to_resolved_path(config) {
+        let content = fs::read_to_string(&key_file)
+            .with_context(|| format!("Could not read key file at {:?}", &key_file))?;
+        let identity: x25519::Identity = content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .ok_or_else(|| anyhow!("Key file at {:?} contains no identity", &key_file))?
+            .parse()
+            .map_err(|error: &str| {
+                anyhow!("Invalid identity in key file {:?}: {error}", &key_file)
+            })?;
+
+        return Ok(JournalCipher::Identity(Box::new(identity)));
+    }
+
+    let env_name = encryption.resolve_passphrase_env();
+    let passphrase = std::env::var(env_name).with_context(|| {
+        format!(
+            "Encryption is configured without key file but no passphrase is given via environment variable {}",
+            env_name
+        )
+    })?;
+
+    Ok(JournalCipher::Passphrase(SecretString::from(passphrase)))
+}
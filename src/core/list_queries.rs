@@ -1,51 +1,191 @@
+use date_validation_types::ValidatedDate;
+
 use crate::{
     core::{
         app_options::AppOptions,
-        dates_names::{MonthlyName, DailyName, ToDateTuple},
-        date_models::find_by::{FindByYearMonthDay, FindByMonthInYear},
+        constants::ARCHIVED_MARK,
+        dates_names::{MonthlyName, DailyName, DateNameForFile, ToDateTuple},
+        date_models::{
+            find_by::{FindByYearMonthDay, FindByMonthInYear},
+            JournalKind, NavigateBy,
+        },
     },
     prelude::*,
 };
 
-use super::{file_access, dates_names::yearly_name::YearlyName};
+use super::{
+    file_access,
+    dates_names::{with_journal_of_kind, yearly_name::YearlyName},
+};
 use super::date_filtering;
 
+/// Lists daily journals of the data folder and the archive folders.
+/// Journals only found in an archive folder are marked as archived.
 pub fn fetch_all_daily_names(
     ymd_listing: &FindByYearMonthDay,
     option: &AppOptions,
 ) -> AppResult<Vec<String>> {
-    let with_valid_format: Vec<DailyName> = file_access::fetch_valid_date_entries(option)?;
-    let filtered_by_ymd = date_filtering::filter_dailies_by_ymd(with_valid_format, ymd_listing);
+    let (active, archived) = fetch_active_and_archived::<DailyName>(option)?;
+    let filtered_by_ymd = date_filtering::filter_dailies_by_ymd(active, ymd_listing);
+    let archived_by_ymd = date_filtering::filter_dailies_by_ymd(archived, ymd_listing);
 
-    let sorted_date_tuple = sort_and_to_string(filtered_by_ymd);
+    let sorted_date_tuple = sort_and_to_string(filtered_by_ymd, archived_by_ymd);
     Ok(sorted_date_tuple)
 }
+/// Lists monthly journals of the data folder and the archive folders.
+/// Journals only found in an archive folder are marked as archived.
 pub fn fetch_all_monthly_names(
     month_in_year: &FindByMonthInYear,
     option: &AppOptions,
 ) -> AppResult<Vec<String>> {
-    let with_valid_format: Vec<MonthlyName> = file_access::fetch_valid_date_entries(option)?;
+    let (active, archived) = fetch_active_and_archived::<MonthlyName>(option)?;
 
-    let filtered_monthlies = date_filtering::filter_monthly_by_ym(with_valid_format, month_in_year);
+    let filtered_monthlies = date_filtering::filter_monthly_by_ym(active, month_in_year);
+    let archived_monthlies = date_filtering::filter_monthly_by_ym(archived, month_in_year);
 
-    let sorted_compact_tuple = sort_and_to_string(filtered_monthlies);
+    let sorted_compact_tuple = sort_and_to_string(filtered_monthlies, archived_monthlies);
 
     Ok(sorted_compact_tuple)
 }
+/// Lists yearly journals of the data folder and the archive folders.
+/// Journals only found in an archive folder are marked as archived.
 pub fn fetch_yearly_names(option: &AppOptions) -> AppResult<Vec<String>> {
-    let with_valid_format: Vec<YearlyName> = file_access::fetch_valid_date_entries(option)?;
+    let (active, archived) = fetch_active_and_archived::<YearlyName>(option)?;
 
-    let sorted_compact_tuple = sort_and_to_string(with_valid_format);
+    let sorted_compact_tuple = sort_and_to_string(active, archived);
 
     Ok(sorted_compact_tuple)
 }
 
-fn sort_and_to_string<T>(mut seq: Vec<T>) -> Vec<String>
+/// Returns the date of the existing journal of the given kind which is selected by the given
+/// navigation. Previous and next journal are searched relative to the journal of the given kind
+/// which contains the given date. Monthly and yearly journals are returned by their first day.
+/// Journals of the data folder and the archive folders are considered.
+/// Returns none if there is no journal in the selected direction.
+pub fn navigate_to(
+    kind: JournalKind,
+    navigate_by: NavigateBy,
+    from: ValidatedDate,
+    option: &AppOptions,
+) -> AppResult<Option<ValidatedDate>> {
+    with_journal_of_kind!(kind, from, |journal| {
+        let (mut all, archived) = fetch_active_and_archived(option)?;
+        all.extend(archived);
+        all.sort();
+
+        match navigate_among(all, navigate_by, &journal) {
+            Some(found) => {
+                let components = found.date_components();
+                let date = ValidatedDate::from_ymd(
+                    components.year(),
+                    components.month().unwrap_or(1),
+                    components.day().unwrap_or(1),
+                )
+                .map_err(AppError::from)?;
+                Ok(Some(date))
+            }
+            None => Ok(None),
+        }
+    })
+}
+
+/// Selects one journal out of the given journals sorted from the oldest to the newest.
+fn navigate_among<T>(sorted: Vec<T>, navigate_by: NavigateBy, from: &T) -> Option<T>
+where
+    T: Ord,
+{
+    match navigate_by {
+        NavigateBy::Last => sorted.into_iter().last(),
+        NavigateBy::Previous => sorted.into_iter().filter(|journal| journal < from).last(),
+        NavigateBy::Next => sorted.into_iter().find(|journal| journal > from),
+        NavigateBy::Random if sorted.is_empty() => None,
+        NavigateBy::Random => {
+            let index = fastrand::usize(..sorted.len());
+            sorted.into_iter().nth(index)
+        }
+    }
+}
+
+/// Returns journals of the data folder and journals which are only found in archive folders.
+/// Journals of an archive folder within the data folder count as archived.
+fn fetch_active_and_archived<T>(option: &AppOptions) -> AppResult<(Vec<T>, Vec<T>)>
+where
+    T: DateNameForFile,
+{
+    let archive_folders = option
+        .load_config()?
+        .map(|config| config.archive_folders())
+        .unwrap_or_default();
+    let mut active = Vec::new();
+    let mut archived = Vec::new();
+    for (journal, path) in file_access::fetch_entries_with_archive::<T>(option)? {
+        if file_access::is_in_archive_folder(&path, &archive_folders) {
+            archived.push(journal);
+        } else {
+            active.push(journal);
+        }
+    }
+
+    Ok((active, archived))
+}
+
+fn sort_and_to_string<T>(active: Vec<T>, archived: Vec<T>) -> Vec<String>
 where
     T: Ord + ToDateTuple,
 {
-    seq.sort();
+    let mut seq: Vec<(T, bool)> = active
+        .into_iter()
+        .map(|journal| (journal, false))
+        .chain(archived.into_iter().map(|journal| (journal, true)))
+        .collect();
+    seq.sort_by(|left, right| left.0.cmp(&right.0));
     seq.reverse();
 
-    seq.into_iter().map(|str| str.to_date_tuple()).collect()
+    seq.into_iter()
+        .map(|(journal, is_archived)| {
+            let tuple = journal.to_date_tuple();
+            if is_archived {
+                format!("{tuple}{ARCHIVED_MARK}")
+            } else {
+                tuple
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod testing {
+    use test_case::test_case;
+
+    use super::*;
+
+    const SORTED: &[&str] = &[
+        "2022_12_30_daily.md",
+        "2023_01_02_daily.md",
+        "2023_01_05_daily.md",
+    ];
+
+    #[test_case(NavigateBy::Last, "2023_01_01_daily.md" => Some("2023 01 05".to_owned()) ; "Should select newest journal")]
+    #[test_case(NavigateBy::Previous, "2023_01_02_daily.md" => Some("2022 12 30".to_owned()) ; "Should select journal before existing one")]
+    #[test_case(NavigateBy::Previous, "2023_01_04_daily.md" => Some("2023 01 02".to_owned()) ; "Should select nearest journal before date")]
+    #[test_case(NavigateBy::Next, "2022_12_31_daily.md" => Some("2023 01 02".to_owned()) ; "Should select nearest journal after date")]
+    #[test_case(NavigateBy::Next, "2023_01_05_daily.md" => None ; "Should select nothing after newest journal")]
+    #[test_case(NavigateBy::Previous, "2022_01_01_daily.md" => None ; "Should select nothing before oldest journal")]
+    fn should_navigate_among_journals(navigate_by: NavigateBy, from: &str) -> Option<String> {
+        let sorted: Vec<DailyName> = SORTED.iter().map(|name| name.parse().unwrap()).collect();
+        let from: DailyName = from.parse().unwrap();
+
+        navigate_among(sorted, navigate_by, &from).map(|found| found.to_date_tuple())
+    }
+
+    #[test]
+    fn should_select_any_journal_randomly() {
+        let sorted: Vec<DailyName> = SORTED.iter().map(|name| name.parse().unwrap()).collect();
+        let from: DailyName = SORTED[0].parse().unwrap();
+
+        let actual = navigate_among(sorted, NavigateBy::Random, &from).unwrap();
+
+        assert!(SORTED.contains(&actual.name()));
+        assert!(navigate_among(Vec::new(), NavigateBy::Random, &from).is_none());
+    }
 }
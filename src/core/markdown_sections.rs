@@ -0,0 +1,293 @@
+use std::path::{Path, PathBuf};
+
+/// Returns the level of the given line if it is a markdown heading like "## Log".
+/// Returns none if the line is no heading.
+pub fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|sign| *sign == '#').count();
+    let rest = &line[level..];
+
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+        Some(level)
+    } else {
+        None
+    }
+}
+
+/// Returns the heading level of every line of the content.
+/// Lines of fenced code blocks like a "# comment" in a shell script are no headings.
+fn heading_levels(lines: &[&str]) -> Vec<Option<usize>> {
+    let mut in_code_block = false;
+
+    lines
+        .iter()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+                None
+            } else if in_code_block {
+                None
+            } else {
+                heading_level(line)
+            }
+        })
+        .collect()
+}
+
+/// Returns the index of the line which is the given heading.
+fn position_of_heading(lines: &[&str], levels: &[Option<usize>], heading: &str) -> Option<usize> {
+    lines
+        .iter()
+        .zip(levels)
+        .position(|(line, level)| level.is_some() && line.trim_end() == heading)
+}
+
+/// Returns the first line with text. Marks of a heading are removed.
+pub fn first_line_excerpt(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(|line| match heading_level(line) {
+            Some(level) => line[level..].trim(),
+            None => line,
+        })
+}
+
+/// Returns the text of the first heading. Returns the first line with text if there is no heading.
+pub fn first_heading_excerpt(content: &str) -> Option<&str> {
+    let lines: Vec<&str> = content.lines().collect();
+    let levels = heading_levels(&lines);
+
+    lines
+        .into_iter()
+        .zip(levels)
+        .filter_map(|(line, level)| level.map(|level| line[level..].trim()))
+        .find(|heading| !heading.is_empty())
+        .or_else(|| first_line_excerpt(content))
+}
+
+/// Returns the line of the heading with the given title.
+/// A title with marks like "## Blockers" must match the line of the heading exactly.
+/// A title without marks like "Blockers" matches the text of a heading of any level
+/// regardless of case.
+pub fn find_heading<'a>(content: &'a str, title: &str) -> Option<&'a str> {
+    let title = title.trim();
+    let with_marks = heading_level(title).is_some();
+    let lines: Vec<&str> = content.lines().collect();
+    let levels = heading_levels(&lines);
+
+    lines
+        .into_iter()
+        .zip(levels)
+        .filter_map(|(line, level)| level.map(|level| (line.trim_end(), level)))
+        .find(|(line, level)| {
+            if with_marks {
+                *line == title
+            } else {
+                line[*level..].trim().eq_ignore_ascii_case(title)
+            }
+        })
+        .map(|(line, _)| line)
+}
+
+/// Returns the content under the given heading without the heading itself.
+/// The section ends before the next heading of the same or a higher level.
+/// Blank lines at the start and the end of the section are removed.
+/// Returns none if the heading is not found. Lines of fenced code blocks are never headings.
+pub fn extract_section(content: &str, heading: &str) -> Option<String> {
+    let heading = heading.trim();
+    let level = heading_level(heading)?;
+    let lines: Vec<&str> = content.lines().collect();
+    let levels = heading_levels(&lines);
+    let start = position_of_heading(&lines, &levels, heading)?;
+
+    let section: Vec<&str> = lines[start + 1..]
+        .iter()
+        .zip(&levels[start + 1..])
+        .take_while(|(_, line_level)| line_level.map_or(true, |other| other > level))
+        .map(|(line, _)| *line)
+        .collect();
+
+    let first = section.iter().position(|line| !line.trim().is_empty());
+    let last = section.iter().rposition(|line| !line.trim().is_empty());
+    match first.zip(last) {
+        Some((first, last)) => Some(section[first..=last].join("\n")),
+        None => Some(String::new()),
+    }
+}
+
+/// Returns a link from a file in the folder `from_folder` to the file `to`.
+/// The link is relative if both lie within the given data folder. Otherwise it is the path of `to`.
+pub fn relative_link(data_folder: &Path, from_folder: &Path, to: &Path) -> String {
+    let relative = from_folder
+        .strip_prefix(data_folder)
+        .ok()
+        .zip(to.strip_prefix(data_folder).ok());
+
+    match relative {
+        Some((from_folder, to)) => {
+            let mut link = PathBuf::new();
+            for _ in from_folder.components() {
+                link.push("..");
+            }
+            link.push(to);
+            link.to_string_lossy().to_string()
+        }
+        None => to.to_string_lossy().to_string(),
+    }
+}
+
+/// Appends the given entry as new line at the end of the content.
+pub fn append_at_end(content: &str, entry: &str) -> String {
+    let mut appended = content.to_owned();
+    if !appended.is_empty() && !appended.ends_with('\n') {
+        appended.push('\n');
+    }
+    appended.push_str(entry);
+    appended.push('\n');
+
+    appended
+}
+
+/// Inserts the given entry as last line under the given heading.
+/// The section of the heading ends before the next heading of the same or a higher level.
+/// Blank lines at the end of the section stay after the inserted entry.
+/// If the heading is not found then the heading is appended with the entry under it.
+/// Lines of fenced code blocks are never headings.
+/// The given heading must be a markdown heading like "## Log". See [`heading_level`]
+pub fn insert_into_section(content: &str, heading: &str, entry: &str) -> String {
+    let heading = heading.trim();
+    let mut lines: Vec<&str> = content.lines().collect();
+    let levels = heading_levels(&lines);
+
+    let start = match position_of_heading(&lines, &levels, heading) {
+        Some(start) => start,
+        None if content.trim().is_empty() => return format!("{heading}\n{entry}\n"),
+        None => return append_at_end(&append_at_end(content, ""), &format!("{heading}\n{entry}")),
+    };
+
+    let level = heading_level(heading).unwrap_or(usize::MAX);
+    let end = levels[start + 1..]
+        .iter()
+        .position(|line_level| line_level.map_or(false, |other| other <= level))
+        .map_or(lines.len(), |after_start| start + 1 + after_start);
+    let insert_at = (start + 1..end)
+        .rev()
+        .take_while(|index| lines[*index].trim().is_empty())
+        .last()
+        .unwrap_or(end);
+
+    lines.insert(insert_at, entry);
+    let mut inserted = lines.join("\n");
+    inserted.push('\n');
+
+    inserted
+}
+
+#[cfg(test)]
+mod testing {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("# Title" => Some(1) ; "Should detect heading of level 1")]
+    #[test_case("### Tasks" => Some(3) ; "Should detect heading of level 3")]
+    #[test_case("##" => Some(2) ; "Should detect empty heading")]
+    #[test_case("#hashtag" => None ; "Should not treat hashtag as heading")]
+    #[test_case("####### Too deep" => None ; "Should not allow more than 6 levels")]
+    #[test_case("Some text" => None ; "Should not treat text as heading")]
+    fn should_detect_heading_level(line: &str) -> Option<usize> {
+        heading_level(line)
+    }
+
+    #[test_case("\n\n## Log \nText" => Some("Log") ; "Should strip marks of heading")]
+    #[test_case("  \nFirst line\nSecond line" => Some("First line") ; "Should skip blank lines")]
+    #[test_case(" \n" => None ; "Should find nothing in blank content")]
+    fn should_return_first_line_as_excerpt(content: &str) -> Option<&str> {
+        first_line_excerpt(content)
+    }
+
+    #[test_case("Intro\n## Title\n# Other" => Some("Title") ; "Should take first heading")]
+    #[test_case("\nFirst line\nSecond line" => Some("First line") ; "Should take first line without heading")]
+    #[test_case("#\nText\n## Title" => Some("Title") ; "Should skip empty heading")]
+    #[test_case("```sh\n# comment\n```\n# Title" => Some("Title") ; "Should skip comment in code block")]
+    fn should_return_first_heading_as_excerpt(content: &str) -> Option<&str> {
+        first_heading_excerpt(content)
+    }
+
+    #[test_case(
+        "# Day\n## Blockers\n\n- slow build\n### Detail\nlinker\n\n## Notes\n- note",
+        "## Blockers" => Some("- slow build\n### Detail\nlinker".to_owned()) ;
+        "Should extract section with sub headings until next heading"
+    )]
+    #[test_case("# Day\n## Blockers\n- last", "## Blockers" => Some("- last".to_owned()) ; "Should extract section at end")]
+    #[test_case("## Blockers\n\n## Notes", "## Blockers" => Some(String::new()) ; "Should extract empty section")]
+    #[test_case("# Day\n## Notes", "## Blockers" => None ; "Should find no missing section")]
+    #[test_case(
+        "## Blockers\n```sh\n# retry build\nmake\n```\n- flaky\n## Notes",
+        "## Blockers" => Some("```sh\n# retry build\nmake\n```\n- flaky".to_owned()) ;
+        "Should keep comment in code block within section"
+    )]
+    #[test_case("Blockers\n- flaky", "Blockers" => None ; "Should not extract under text which is no heading")]
+    fn should_extract_section(content: &str, heading: &str) -> Option<String> {
+        extract_section(content, heading)
+    }
+
+    #[test_case("# Day\n### Blockers\n", "## Blockers" => None ; "Should match heading with marks exactly")]
+    #[test_case("# Day\n## Blockers\n", "## Blockers" => Some("## Blockers".to_owned()) ; "Should find heading with marks")]
+    #[test_case("# Day\n### blockers \n", "Blockers" => Some("### blockers".to_owned()) ; "Should find title on any level regardless of case")]
+    #[test_case("# Day\nBlockers\n", "Blockers" => None ; "Should not take text for heading")]
+    #[test_case("```\n# Blockers\n```\n", "Blockers" => None ; "Should not take comment in code block for heading")]
+    fn should_find_heading(content: &str, title: &str) -> Option<String> {
+        find_heading(content, title).map(str::to_owned)
+    }
+
+    #[test_case("/data/2023", "/data/2022/2022_03_10_daily.md" => "../2022/2022_03_10_daily.md" ; "Should link to other folder in data folder")]
+    #[test_case("/data", "/data/2022_03_10_daily.md" => "2022_03_10_daily.md" ; "Should link to same folder")]
+    #[test_case("/data", "/archive/2022_03_10_daily.md" => "/archive/2022_03_10_daily.md" ; "Should link outside of data folder by path")]
+    fn should_create_relative_link(from_folder: &str, to: &str) -> String {
+        relative_link(Path::new("/data"), Path::new(from_folder), Path::new(to))
+    }
+
+    #[test_case("" => "entry\n" ; "Should append to empty content")]
+    #[test_case("text" => "text\nentry\n" ; "Should add missing new line before entry")]
+    #[test_case("text\n" => "text\nentry\n" ; "Should append after last line")]
+    fn should_append_at_end(content: &str) -> String {
+        append_at_end(content, "entry")
+    }
+
+    #[test_case(
+        "# Day\n\n## Log\n- first\n\n## Notes\n",
+        "## Log" => "# Day\n\n## Log\n- first\n- entry\n\n## Notes\n" ;
+        "Should insert before blank lines and next heading"
+    )]
+    #[test_case(
+        "# Day\n\n## Log\n- first\n### Detail\n- detail\n## Notes\n",
+        "## Log" => "# Day\n\n## Log\n- first\n### Detail\n- detail\n- entry\n## Notes\n" ;
+        "Should include sub headings in section"
+    )]
+    #[test_case(
+        "# Day\n\n## Log",
+        "## Log" => "# Day\n\n## Log\n- entry\n" ;
+        "Should insert under heading at end"
+    )]
+    #[test_case(
+        "# Day\n\n## Notes\n",
+        "## Log" => "# Day\n\n## Notes\n\n## Log\n- entry\n" ;
+        "Should append missing heading with entry"
+    )]
+    #[test_case("", "## Log" => "## Log\n- entry\n" ; "Should create heading in empty content")]
+    #[test_case(
+        "## Log\n```sh\n# comment\n```\n## Notes\n",
+        "## Log" => "## Log\n```sh\n# comment\n```\n- entry\n## Notes\n" ;
+        "Should not end section at comment in code block"
+    )]
+    #[test_case(
+        "```md\n## Log\n```\n",
+        "## Log" => "```md\n## Log\n```\n\n## Log\n- entry\n" ;
+        "Should not insert under heading in code block"
+    )]
+    fn should_insert_into_section(content: &str, heading: &str) -> String {
+        insert_into_section(content, heading, "- entry")
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use derive_new::new;
+
+use crate::cli::migrate_command::MigrateArg;
+use crate::core::app_config::AppConfig;
+use crate::core::app_options::AppOptions;
+use crate::core::constants::MIGRATION_STAGING_FOLDER;
+use crate::core::file_access;
+use crate::prelude::*;
+
+use super::dates_names::{
+    yearly_name::YearlyName, DailyName, DateNameForFile, JournalExtension, MonthlyName,
+    NamingPattern,
+};
+
+#[derive(Debug, PartialEq, Eq, Getters, new)]
+#[getset(get = "pub")]
+/// Move of one journal from its current location to the location of the new scheme.
+pub struct PlannedMove {
+    from: PathBuf,
+    to: PathBuf,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MigrationConflict {
+    /// There is already a file at the target which is not moved away during the migration.
+    TargetExists(PlannedMove),
+    /// Several journals would be moved to the same target.
+    SameTarget { from: Vec<PathBuf>, to: PathBuf },
+}
+
+#[derive(Debug, Default, Getters)]
+#[getset(get = "pub")]
+/// All moves needed to bring the journals of the data folder into the configured scheme.
+pub struct MigrationPlan {
+    /// Journals which need to be moved. Journals already at their place are not listed.
+    moves: Vec<PlannedMove>,
+    conflicts: Vec<MigrationConflict>,
+    /// Files which could not be recognized as any kind of journal.
+    unparseable: Vec<PathBuf>,
+}
+
+#[derive(Debug)]
+pub enum MigrationResult {
+    /// User did cancel the migration when asked for confirmation
+    NoConfirmation,
+    /// Nothing was moved because the plan has conflicts.
+    Conflicts(MigrationPlan),
+    /// Migration was only simulated. Contains the moves which would have been done.
+    DryRun(MigrationPlan),
+    /// All planned moves were done.
+    Migrated(MigrationPlan),
+}
+
+/// Patterns by which journals are recognized in addition to the configured naming pattern
+/// and the default format.
+struct SourcePatterns {
+    daily: Option<NamingPattern>,
+    monthly: Option<NamingPattern>,
+    yearly: Option<NamingPattern>,
+}
+
+/// Moves every recognized journal to the location given by the configured naming pattern and
+/// layout of the data folder.
+/// Either all planned moves are done or none. Nothing is moved if the plan has conflicts.
+/// ## Errors
+/// - A given naming pattern is invalid.
+/// - A journal could not be moved. All already moved journals are moved back in this case.
+pub fn migrate(
+    migrate_arg: &MigrateArg,
+    option: &AppOptions,
+    on_confirmation: impl Fn(&MigrationPlan) -> AppResult<bool>,
+) -> AppResult<MigrationResult> {
+    let plan = plan_migration(migrate_arg, option)?;
+
+    if !plan.conflicts.is_empty() {
+        return Ok(MigrationResult::Conflicts(plan));
+    }
+
+    if migrate_arg.dry_run() {
+        return Ok(MigrationResult::DryRun(plan));
+    }
+
+    if plan.moves.is_empty() {
+        return Ok(MigrationResult::Migrated(plan));
+    }
+
+    let wants_to_migrate = if !migrate_arg.skip_confirmation() {
+        on_confirmation(&plan)?
+    } else {
+        true
+    };
+
+    if !wants_to_migrate {
+        return Ok(MigrationResult::NoConfirmation);
+    }
+
+    apply_moves(&plan.moves, option)?;
+
+    Ok(MigrationResult::Migrated(plan))
+}
+
+/// Returns which journals need to be moved where without moving anything.
+pub fn plan_migration(migrate_arg: &MigrateArg, option: &AppOptions) -> AppResult<MigrationPlan> {
+    let sources = SourcePatterns {
+        daily: parse_source_pattern(migrate_arg.from_daily_pattern(), true, true)?,
+        monthly: parse_source_pattern(migrate_arg.from_monthly_pattern(), true, false)?,
+        yearly: parse_source_pattern(migrate_arg.from_yearly_pattern(), false, false)?,
+    };
+    let config = option.load_config()?;
+
+    let mut plan = MigrationPlan::default();
+    let mut all_targets: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+
+    for path in file_access::get_all_journal_paths(option)? {
+        match target_for(&path, &sources, config, option)? {
+            Some(target) if target != path => all_targets.entry(target).or_default().push(path),
+            Some(_) => (),
+            None => plan.unparseable.push(path),
+        }
+    }
+
+    let moved_away: HashSet<PathBuf> = all_targets.values().flatten().cloned().collect();
+    let mut moves = Vec::new();
+    for (to, mut from) in all_targets {
+        if from.len() > 1 {
+            from.sort();
+            plan.conflicts
+                .push(MigrationConflict::SameTarget { from, to });
+        } else if to.try_exists()? && !moved_away.contains(&to) {
+            let planned = PlannedMove::new(from.remove(0), to);
+            plan.conflicts
+                .push(MigrationConflict::TargetExists(planned));
+        } else {
+            moves.push(PlannedMove::new(from.remove(0), to));
+        }
+    }
+
+    moves.sort_by(|left, right| left.from.cmp(&right.from));
+    plan.moves = moves;
+    plan.unparseable.sort();
+
+    Ok(plan)
+}
+
+fn parse_source_pattern(
+    given: &Option<String>,
+    with_month: bool,
+    with_day: bool,
+) -> AppResult<Option<NamingPattern>> {
+    given
+        .as_ref()
+        .map(|raw| {
+            let pattern: NamingPattern = raw.parse()?;
+            pattern.ensure_placeholders(with_month, with_day)?;
+            Ok(pattern)
+        })
+        .transpose()
+}
+
+fn target_for(
+    path: &Path,
+    sources: &SourcePatterns,
+    config: Option<&AppConfig>,
+    option: &AppOptions,
+) -> AppResult<Option<PathBuf>> {
+    let file_name = match path.file_name().and_then(|name| name.to_str()) {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+    let ext: JournalExtension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| ext.parse().ok())
+        .unwrap_or_default();
+
+    if let Some(daily) = recognize::<DailyName>(file_name, sources.daily.as_ref(), config) {
+        return file_access::configured_path_for(&daily, ext, option).map(Some);
+    }
+    if let Some(monthly) = recognize::<MonthlyName>(file_name, sources.monthly.as_ref(), config) {
+        return file_access::configured_path_for(&monthly, ext, option).map(Some);
+    }
+    if let Some(yearly) = recognize::<YearlyName>(file_name, sources.yearly.as_ref(), config) {
+        return file_access::configured_path_for(&yearly, ext, option).map(Some);
+    }
+
+    Ok(None)
+}
+
+fn recognize<T>(
+    file_name: &str,
+    source: Option<&NamingPattern>,
+    config: Option<&AppConfig>,
+) -> Option<T>
+where
+    T: DateNameForFile,
+{
+    source
+        .and_then(|pattern| T::from_pattern(pattern, file_name))
+        .or_else(|| file_access::parse_journal_name(file_name, config))
+}
+
+/// Moves all journals at first into a staging folder and from there to their targets.
+/// This way journals can take over the location of other moved journals.
+/// If one move fails, all done moves are reverted.
+fn apply_moves(moves: &[PlannedMove], option: &AppOptions) -> AppResult {
+    let staging = option.get_data_path()?.join(MIGRATION_STAGING_FOLDER);
+    if staging.try_exists()? {
+        bail!(
+            "Folder {:?} of a former migration still exists. Check and remove it before migrating again",
+            &staging
+        );
+    }
+    fs::create_dir_all(&staging)
+        .with_context(|| format!("Could not create staging folder at {:?}", &staging))?;
+
+    let mut done: Vec<(PathBuf, PathBuf)> = Vec::with_capacity(moves.len() * 2);
+    if let Err(error) = move_via_staging(moves, &staging, &mut done) {
+        roll_back(&done);
+        _ = fs::remove_dir(&staging);
+        return Err(error.context("Migration failed. All moved journals were moved back"));
+    }
+
+    fs::remove_dir(&staging)
+        .with_context(|| format!("Could not remove staging folder at {:?}", &staging))?;
+
+    Ok(())
+}
+
+fn move_via_staging(
+    moves: &[PlannedMove],
+    staging: &Path,
+    done: &mut Vec<(PathBuf, PathBuf)>,
+) -> AppResult {
+    for (index, planned) in moves.iter().enumerate() {
+        let staged = staging.join(index.to_string());
+        fs::rename(&planned.from, &staged)
+            .with_context(|| format!("Could not move journal {:?}", &planned.from))?;
+        done.push((planned.from.clone(), staged));
+    }
+
+    for (index, planned) in moves.iter().enumerate() {
+        let staged = staging.join(index.to_string());
+        if let Some(folder) = planned.to.parent() {
+            fs::create_dir_all(folder)
+                .with_context(|| format!("Could not create folder {:?}", folder))?;
+        }
+        fs::rename(&staged, &planned.to)
+            .with_context(|| format!("Could not move journal to {:?}", &planned.to))?;
+        done.push((staged, planned.to.clone()));
+    }
+
+    Ok(())
+}
+
+fn roll_back(done: &[(PathBuf, PathBuf)]) {
+    for (from, to) in done.iter().rev() {
+        if let Err(error) = fs::rename(to, from) {
+            error!(
+                "Could not move {:?} back to {:?} after failed migration.\n Error: {}",
+                to, from, error
+            );
+        }
+    }
+}
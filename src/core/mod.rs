@@ -1,14 +1,29 @@
 pub mod app_config;
 pub mod app_options;
+pub mod append_actions;
+pub mod cat_actions;
 pub mod date_models;
 pub mod delete_actions;
+pub mod doctor_actions;
 pub mod list_queries;
+pub mod migrate_actions;
+pub mod notebook_actions;
+pub mod on_this_day_actions;
 pub mod open_actions;
+pub mod path_actions;
 pub mod process_handling;
+pub mod rollup_actions;
+pub mod section_actions;
+pub mod terminal_output;
+pub mod trash_actions;
+pub mod write_actions;
 
+mod data_layout;
 mod date_filtering;
 mod dates_names;
 mod file_access;
+mod journal_store;
+mod markdown_sections;
 mod template;
 
 use self::dates_names::daily_names::DailyName;
@@ -25,6 +40,28 @@ pub mod constants {
     pub const YEARLY_LABEL_IN_NAME: &str = "yearly";
     pub const DIGIT_SEP: &str = "_";
     pub const SIGN_FOR_FROM_CONF_FOLDER: char = '+';
+    /// Placeholder in the layout of the data folder for the year of a journal.
+    pub const LAYOUT_YEAR: &str = "{year}";
+    /// Placeholder in the layout of the data folder for the month of a journal.
+    pub const LAYOUT_MONTH: &str = "{month}";
+    /// Placeholder in the layout of the data folder for the day of a journal.
+    pub const LAYOUT_DAY: &str = "{day}";
+    /// Placeholder in a naming pattern for the 4 digit year of a journal.
+    pub const NAME_PATTERN_YEAR: &str = "{yyyy}";
+    /// Placeholder in a naming pattern for the 2 digit month of a journal.
+    pub const NAME_PATTERN_MONTH: &str = "{mm}";
+    /// Placeholder in a naming pattern for the 2 digit day of a journal.
+    pub const NAME_PATTERN_DAY: &str = "{dd}";
+    /// Placeholder in a naming pattern for the extension of a journal.
+    pub const NAME_PATTERN_EXT: &str = "{ext}";
+    /// Folder inside the data folder where deleted journals are moved to.
+    pub const TRASH_FOLDER: &str = ".trash";
+    /// Folder inside the data folder where journals are kept in between during a migration.
+    pub const MIGRATION_STAGING_FOLDER: &str = ".migration";
+    /// Appended to journals in listings which are only found in an archive folder.
+    pub const ARCHIVED_MARK: &str = " (archived)";
+    /// Extension of the file which holds the deletion metadata of a trashed journal.
+    pub const TRASH_META_EXT: &str = "toml";
 
     pub const MONTH_LOWER_BOUND: u32 = 1;
     pub const MONTH_UPPER_BOUND: u32 = 12;
@@ -34,6 +71,14 @@ pub mod constants {
 
     pub const ENV_PREFIX: &str = "JOURNAL_RUSTER";
     pub const CONF_FILE_NAME: &str = "config.toml";
+    /// Name of a config file for a project. It is searched in the current folder and its parents.
+    pub const PROJECT_CONF_FILE_NAME: &str = ".daily_ruster_man.toml";
+    /// Folder with the config file for all users of the system.
+    pub const SYSTEM_CONF_FOLDER: &str = "/etc/daily_ruster_man";
+    /// Environment variable for the passphrase of encrypted journals if none is configured.
+    pub const DEFAULT_PASSPHRASE_ENV: &str = "RUSTER_JOURNAL_PASSPHRASE";
+    /// Every journal encrypted via age starts with these bytes.
+    pub const ENCRYPTED_JOURNAL_MAGIC: &[u8] = b"age-encryption.org/v1";
 
     /// Placeholder value for which a journal inserts its day.
     pub const DAY_VAR_NAME: &str = "DAY_JOURNAL";
@@ -41,6 +86,30 @@ pub mod constants {
     pub const MONTH_VAR_NAME: &str = "MONTH_JOURNAL";
     /// Placeholder value for which a journal inserts its year.
     pub const YEAR_VAR_NAME: &str = "YEAR_JOURNAL";
+    /// Placeholder value for which a daily journal inserts links to the journals of the same
+    /// day in previous years.
+    pub const ON_THIS_DAY_VAR_NAME: &str = "ON_THIS_DAY";
+    /// Placeholder value for which a monthly journal inserts links to the daily journals of its
+    /// month.
+    pub const MONTH_ROLLUP_VAR_NAME: &str = "MONTH_ROLLUP";
+    /// Placeholder value for which a yearly journal inserts links to the monthly journals of its
+    /// year.
+    pub const YEAR_ROLLUP_VAR_NAME: &str = "YEAR_ROLLUP";
+
+    /// Header printed before every journal by the cat command.
+    pub const DEFAULT_CAT_HEADER: &str = "=== {date} {kind} ===";
+    /// Placeholder in the header of the cat command for the date of a journal.
+    pub const CAT_HEADER_DATE: &str = "{date}";
+    /// Placeholder in the header of the cat command for the kind of a journal.
+    pub const CAT_HEADER_KIND: &str = "{kind}";
+    /// Placeholder in the header of the cat command for the file name of a journal.
+    pub const CAT_HEADER_NAME: &str = "{name}";
+
+    /// Pager for rendered journals if $PAGER is not set.
+    /// It prints output which fits on one screen directly.
+    pub const DEFAULT_PAGER: &str = "less -FRX";
+    /// Number of days of a range up to which missing daily journals are created without confirmation.
+    pub const MAX_DAYS_TO_CREATE_WITHOUT_CONFIRMATION: u64 = 31;
 
     /// Marks start of an builtin value for placeholder
     /// Example: {{ is prefix for  builtin var {{SOME_BUILTIN_VAR}}
@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+
+use derive_new::new;
+
+use crate::core::app_options::AppOptions;
+use crate::core::file_access;
+use crate::prelude::*;
+
+use super::dates_names::{yearly_name::YearlyName, DailyName, MonthlyName};
+
+#[derive(Debug, Getters, CopyGetters, new)]
+/// Notebook from the config file with the number of its journals.
+pub struct NotebookSummary {
+    #[getset(get = "pub")]
+    name: String,
+    #[getset(get = "pub")]
+    data_path: PathBuf,
+    #[getset(get_copy = "pub")]
+    /// Number of daily, monthly and yearly journals in the data folder of the notebook.
+    journal_count: usize,
+    #[getset(get_copy = "pub")]
+    /// True if this notebook is used by the current options.
+    is_selected: bool,
+}
+
+/// Returns all notebooks of the config file ordered by their name.
+/// Returns an empty list if there is no config file or no notebook in it.
+/// ## Errors
+/// - Config file could not be loaded.
+/// - A data folder of a notebook exists but could not be read.
+pub fn list_notebooks(option: &AppOptions) -> AppResult<Vec<NotebookSummary>> {
+    let config = match option.load_config()? {
+        Some(loaded) => loaded,
+        None => return Ok(Vec::new()),
+    };
+    let notebooks = match config.notebooks() {
+        Some(notebooks) => notebooks,
+        None => return Ok(Vec::new()),
+    };
+
+    notebooks
+        .keys()
+        .map(|name| {
+            let for_notebook = option.for_notebook(name);
+            let data_path = for_notebook.get_data_path()?;
+            let journal_count = if data_path.try_exists()? {
+                count_journals(&for_notebook)?
+            } else {
+                0
+            };
+            let is_selected = config.selected_notebook().as_ref() == Some(name);
+
+            Ok(NotebookSummary::new(
+                name.clone(),
+                data_path,
+                journal_count,
+                is_selected,
+            ))
+        })
+        .collect()
+}
+
+fn count_journals(option: &AppOptions) -> AppResult<usize> {
+    let daily = file_access::fetch_valid_date_entries::<DailyName>(option)?.len();
+    let monthly = file_access::fetch_valid_date_entries::<MonthlyName>(option)?.len();
+    let yearly = file_access::fetch_valid_date_entries::<YearlyName>(option)?.len();
+
+    Ok(daily + monthly + yearly)
+}
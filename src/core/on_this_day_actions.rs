@@ -0,0 +1,90 @@
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+
+use chrono::{Datelike, NaiveDate};
+use date_validation_types::ValidatedDate;
+use derive_new::new;
+
+use crate::core::app_options::AppOptions;
+use crate::prelude::*;
+
+use super::dates_names::DailyName;
+use super::{file_access, journal_store, markdown_sections};
+
+#[derive(Debug, Getters, CopyGetters, new)]
+/// Daily journal of a previous year with its content.
+pub struct PastJournal {
+    #[getset(get_copy = "pub")]
+    date: NaiveDate,
+    #[getset(get = "pub")]
+    path: PathBuf,
+    #[getset(get = "pub")]
+    content: String,
+}
+
+impl PastJournal {
+    /// Returns how many years the journal lies before the given date.
+    pub fn years_before(&self, date: ValidatedDate) -> u32 {
+        date.year().saturating_sub(self.date.year() as u32)
+    }
+}
+
+/// Returns the daily journals of the same calendar day in all previous years from the newest
+/// to the oldest one. If `same_week` is true then all daily journals within the same week of
+/// previous years are returned instead. Journals of archive folders are included.
+/// ## Errors
+/// - A found journal could not be read.
+pub fn fetch_on_this_day(
+    date: ValidatedDate,
+    same_week: bool,
+    option: &AppOptions,
+) -> AppResult<Vec<PastJournal>> {
+    let given: NaiveDate = date.into();
+    let is_on_this_day = |past: &NaiveDate| {
+        if same_week {
+            past.iso_week().year() < given.iso_week().year()
+                && past.iso_week().week() == given.iso_week().week()
+        } else {
+            past.year() < given.year() && past.month() == given.month() && past.day() == given.day()
+        }
+    };
+
+    let mut found: Vec<(NaiveDate, PathBuf)> =
+        file_access::fetch_entries_with_archive::<DailyName>(option)?
+            .into_iter()
+            .map(|(journal, path)| (journal.date().into(), path))
+            .filter(|(past, _)| is_on_this_day(past))
+            .collect();
+    found.sort_by_key(|(past, _)| Reverse(*past));
+
+    let mut past_journals = Vec::with_capacity(found.len());
+    for (past, path) in found {
+        if let Some(content) = journal_store::read_journal(&path, option)? {
+            past_journals.push(PastJournal::new(past, path, content));
+        }
+    }
+
+    Ok(past_journals)
+}
+
+/// Returns a markdown list with a link and the first line of every daily journal of the same
+/// calendar day in previous years. Links are relative to the folder of the given journal if
+/// possible. Used for the builtin variable ON_THIS_DAY.
+pub fn on_this_day_as_links(journal: &DailyName, option: &AppOptions) -> AppResult<String> {
+    let own_path = file_access::peek_journal_path(journal, option)?;
+    let own_folder = own_path.parent().unwrap_or(Path::new(""));
+    let data_folder = option.get_data_path()?;
+
+    let lines: Vec<String> = fetch_on_this_day(journal.date(), false, option)?
+        .iter()
+        .map(|past| {
+            let link = markdown_sections::relative_link(&data_folder, own_folder, past.path());
+            let excerpt = markdown_sections::first_line_excerpt(past.content()).unwrap_or("");
+            format!("- [{}]({}) {}", past.date(), link, excerpt)
+                .trim_end()
+                .to_owned()
+        })
+        .collect();
+
+    Ok(lines.join("\n"))
+}
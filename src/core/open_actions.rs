@@ -1,9 +1,12 @@
-use std::{fs, io};
-use std::path::Path;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 
-use chrono::{Local, Datelike};
+use chrono::{Local, Datelike, NaiveDate};
 use crate::cli::edit_argument::EditCommonArgs;
-use crate::core::template;
+use crate::core::constants::{
+    MAX_DAYS_TO_CREATE_WITHOUT_CONFIRMATION, PREFIX_FOR_BUITLIN_VAR, SUFFIX_FOR_BUITLIN_VAR,
+};
+use crate::core::template::{self, PlaceholderTemplate};
 use crate::prelude::*;
 use crate::core::{app_options::AppOptions, date_models::open_by::OpenByMonthInYear};
 use super::app_config::AppConfig;
@@ -11,7 +14,7 @@ use super::dates_names::ResolvePlaceholders;
 use super::process_handling::ProcessExecuter;
 use date_validation_types::{ValidatedDate, ValidatedYear};
 use super::{
-    file_access, DailyName,
+    file_access, journal_store, DailyName,
     dates_names::{MonthlyName, DateNameForFile, yearly_name::YearlyName, InitialabeFromTemplate},
 };
 
@@ -68,45 +71,139 @@ fn open_date_with_editor<T>(
 where
     T: DateNameForFile + InitialabeFromTemplate + ResolvePlaceholders,
 {
-    let to_open = file_access::create_new_path_for(journal.name(), option)?;
+    let existing = file_access::find_existing_journal(&journal, option)?;
+    let archived = match existing {
+        Some(_) => None,
+        None => file_access::find_archived_journal(&journal, option)?,
+    };
 
-    let editor_to_use = edit_option.resolve_editor(option).unwrap_or_else(|error| {
-        warn!(
-            "Falling back to default editor {} due to error in loading config file correctly.\n {}",
-            EditCommonArgs::DEFAUTL_EDITOR,
-            error,
-        );
+    if edit_option.show_only() {
+        let content = match existing.or(archived) {
+            Some(found) => journal_store::read_journal(&found, option)?,
+            None => None,
+        };
+        if content.is_none() {
+            info!("No journal created to show so far");
+        }
+        return Ok(content);
+    }
 
-        EditCommonArgs::DEFAUTL_EDITOR.to_owned()
-    });
+    let to_open = match (existing, archived) {
+        (Some(existing), _) => existing,
+        (None, Some(archived)) if edit_option.restore_archived() => {
+            let restored = file_access::restore_archived_journal(&journal, &archived, option)?;
+            info!("Copied archived journal {:?} to {:?}", &archived, &restored);
+            restored
+        }
+        (None, Some(archived)) => bail!(
+            "Journal is archived at {:?}. Use --restore-archived to copy it into the data folder and open it",
+            archived
+        ),
+        (None, None) => file_access::resolve_journal_path(&journal, option)?,
+    };
+
+    let editor_to_use = resolve_editor_or_default(option, edit_option);
 
-    let initialize_content_with_templates = !edit_option.show_only() && !to_open.exists();
+    let initialize_content_with_templates = !to_open.exists();
     if initialize_content_with_templates {
         info!("No journal created so far at {:?}", &to_open);
         try_write_template_from_config(&to_open, journal, option)?;
     }
 
-    if edit_option.show_only() {
-        return just_load_journal(&to_open);
+    journal_store::edit_journals(process_executer, &editor_to_use, &[&to_open], option)?;
+
+    Ok(None)
+}
+
+/// Opens all daily journals from `from` to `to` together in one session of the editor.
+/// Missing journals are created from their template first unless `existing_only` is true.
+/// Journals which are only found in an archive folder are skipped unless `--restore-archived` is
+/// given. Returns the paths of the opened journals. The editor is not started if there are none.
+/// If missing journals are to be created for a range longer than
+/// [`MAX_DAYS_TO_CREATE_WITHOUT_CONFIRMATION`](crate::core::constants::MAX_DAYS_TO_CREATE_WITHOUT_CONFIRMATION) days then `confirm_long_range` is asked with
+/// the number of days of the range first.
+/// ## Errors
+/// - `from` is after `to`
+/// - The range is too long and was not confirmed.
+/// - Could not read the data folder or could not create a missing journal
+pub fn open_days_in_range(
+    process_executer: &impl ProcessExecuter,
+    from: ValidatedDate,
+    to: ValidatedDate,
+    existing_only: bool,
+    option: &AppOptions,
+    edit_option: &EditCommonArgs,
+    confirm_long_range: impl FnOnce(u64) -> AppResult<bool>,
+) -> AppResult<Vec<PathBuf>> {
+    let (from, to): (NaiveDate, NaiveDate) = (from.into(), to.into());
+    if from > to {
+        bail!("Start {} of the range is after its end {}", from, to);
     }
 
-    process_executer.start_program(option, &editor_to_use, &to_open)?;
+    let days = (to - from).num_days().unsigned_abs() + 1;
+    if !existing_only
+        && days > MAX_DAYS_TO_CREATE_WITHOUT_CONFIRMATION
+        && !confirm_long_range(days)?
+    {
+        bail!(
+            "Range from {} to {} has {} days which is more than {} days. Use --existing-only to only open existing journals",
+            from,
+            to,
+            days,
+            MAX_DAYS_TO_CREATE_WITHOUT_CONFIRMATION
+        );
+    }
 
-    return Ok(None);
+    let mut to_open = Vec::new();
+    for date in from.iter_days().take_while(|date| *date <= to) {
+        let journal: DailyName = ValidatedDate::from(date).into();
+        if let Some(existing) = file_access::find_existing_journal(&journal, option)? {
+            to_open.push(existing);
+            continue;
+        }
 
-    fn just_load_journal(to_open: &Path) -> OpenResult {
-        match fs::read_to_string(to_open) {
-            Ok(content) => Ok(Some(content)),
-            Err(error) => {
-                if error.kind() == io::ErrorKind::NotFound {
-                    info!("No journal created to show so far");
-                    Ok(None)
-                } else {
-                    Err(AppError::new(error))
-                }
+        match file_access::find_archived_journal(&journal, option)? {
+            Some(archived) if edit_option.restore_archived() => {
+                let restored = file_access::restore_archived_journal(&journal, &archived, option)?;
+                info!("Copied archived journal {:?} to {:?}", &archived, &restored);
+                to_open.push(restored);
+            }
+            Some(archived) => warn!(
+                "Skipped journal archived at {:?}. Use --restore-archived to open it too",
+                archived
+            ),
+            None if existing_only => debug!("Skipped missing journal for {}", date),
+            None => {
+                let path = file_access::resolve_journal_path(&journal, option)?;
+                info!("No journal created so far at {:?}", &path);
+                try_write_template_from_config(&path, journal, option)?;
+                to_open.push(path);
             }
         }
     }
+
+    if to_open.is_empty() {
+        info!("No journals to open within the range");
+        return Ok(to_open);
+    }
+
+    let editor_to_use = resolve_editor_or_default(option, edit_option);
+    let paths: Vec<&Path> = to_open.iter().map(PathBuf::as_path).collect();
+    journal_store::edit_journals(process_executer, &editor_to_use, &paths, option)?;
+
+    Ok(to_open)
+}
+
+fn resolve_editor_or_default(option: &AppOptions, edit_option: &EditCommonArgs) -> String {
+    edit_option.resolve_editor(option).unwrap_or_else(|error| {
+        warn!(
+            "Falling back to default editor {} due to error in loading config file correctly.\n {}",
+            EditCommonArgs::DEFAUTL_EDITOR,
+            error,
+        );
+
+        EditCommonArgs::DEFAUTL_EDITOR.to_owned()
+    })
 }
 
 fn try_write_template_from_config(
@@ -114,26 +211,43 @@ fn try_write_template_from_config(
     journal: impl InitialabeFromTemplate + ResolvePlaceholders,
     option: &AppOptions,
 ) -> AppResult {
-    let config = option.load_config()?;
-    if let Some(loaded) = config {
-        let maybe_template_content = try_create_template(loaded, &journal)?;
-
-        if let Some(content) = maybe_template_content {
-            debug!("Used template content:\n{}", content);
-            if !option.run_editor_dry() {
-                fs::write(to_open, content)?;
-            }
-            return Ok(());
+    if let Some(content) = initial_content_from_template(&journal, option)? {
+        debug!("Used template content:\n{}", content);
+        if !option.run_editor_dry() {
+            journal_store::write_journal(to_open, &content, option)?;
         }
-        debug!("No template found to be used");
     }
 
     Ok(())
 }
 
+/// Returns the content of a new journal created by the configured template of its kind.
+/// Returns none if there is no config file or no template for this kind of journal.
+pub(crate) fn initial_content_from_template<T>(
+    journal: &T,
+    option: &AppOptions,
+) -> AppResult<Option<String>>
+where
+    T: ResolvePlaceholders + InitialabeFromTemplate,
+{
+    let content = match option.load_config()? {
+        Some(loaded) => try_create_template(loaded, journal, option)?,
+        None => None,
+    };
+    if content.is_none() {
+        debug!("No template found to be used");
+    }
+
+    Ok(content)
+}
+
 /// Tries to return an intial content of a journal which was created by a template.
 /// In success a none can be returned if there is no template file at the given `template_path`.
-fn try_create_template<T>(app_config: &AppConfig, journal: &T) -> AppResult<Option<String>>
+fn try_create_template<T>(
+    app_config: &AppConfig,
+    journal: &T,
+    option: &AppOptions,
+) -> AppResult<Option<String>>
 where
     T: ResolvePlaceholders + InitialabeFromTemplate,
 {
@@ -147,6 +261,19 @@ where
     };
     debug!("Augmenting template with placeholders from config file");
     let mut placeholders = app_config.create_template_placeholder_for(journal);
+    for placeholder in placeholders.values_mut() {
+        if let PlaceholderTemplate::DirectValue(value) = placeholder {
+            let builtin = value
+                .strip_prefix(PREFIX_FOR_BUITLIN_VAR)
+                .and_then(|inner| inner.strip_suffix(SUFFIX_FOR_BUITLIN_VAR));
+            if let Some(from_journals) = match builtin {
+                Some(builtin) => journal.resolve_from_journals(builtin, option)?,
+                None => None,
+            } {
+                *value = Cow::Owned(from_journals);
+            }
+        }
+    }
     let maybe_template_content = app_config.try_get_template_file_content(&template_path)?;
     if let Some(content) = maybe_template_content {
         let augmented_with_placeholders =
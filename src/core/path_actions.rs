@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use date_validation_types::ValidatedDate;
+
+use crate::core::app_options::AppOptions;
+use crate::core::date_models::JournalKind;
+use crate::prelude::*;
+
+use super::dates_names::{
+    with_journal_of_kind, DateNameForFile, InitialabeFromTemplate, ResolvePlaceholders,
+};
+use super::{file_access, journal_store, write_actions};
+
+/// Returns the absolute path of the journal of the given kind which contains the given date.
+/// For a missing journal it is the path a new journal would get.
+/// If `create` is true then a missing journal is created from its template without an editor.
+/// ## Errors
+/// - With `create` the journal is only found in an archive folder or could not be written.
+pub fn journal_path(
+    date: ValidatedDate,
+    kind: JournalKind,
+    create: bool,
+    option: &AppOptions,
+) -> AppResult<PathBuf> {
+    let path = with_journal_of_kind!(kind, date, |journal| if create {
+        create_if_missing(&journal, option)?
+    } else {
+        file_access::peek_journal_path(&journal, option)?
+    });
+
+    to_absolute(path)
+}
+
+/// Returns true if the journal of the given kind which contains the given date exists in the
+/// data folder.
+pub fn journal_exists(
+    date: ValidatedDate,
+    kind: JournalKind,
+    option: &AppOptions,
+) -> AppResult<bool> {
+    let found = with_journal_of_kind!(kind, date, |journal| {
+        file_access::find_existing_journal(&journal, option)?
+    });
+
+    Ok(found.is_some())
+}
+
+fn create_if_missing<T>(journal: &T, option: &AppOptions) -> AppResult<PathBuf>
+where
+    T: DateNameForFile + InitialabeFromTemplate + ResolvePlaceholders,
+{
+    let (path, existing) = write_actions::find_journal_to_change(journal, option)?;
+    if existing.is_none() {
+        let content = write_actions::existing_or_template(journal, None, option)?;
+        journal_store::write_journal(&path, &content, option)?;
+    }
+
+    Ok(path)
+}
+
+fn to_absolute(path: PathBuf) -> AppResult<PathBuf> {
+    if path.is_absolute() {
+        Ok(path)
+    } else {
+        let current = std::env::current_dir().context("Could not determine current folder")?;
+        Ok(current.join(path))
+    }
+}
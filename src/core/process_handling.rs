@@ -1,5 +1,6 @@
 use crate::prelude::*;
 
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::path::Path;
 use std::process::Command;
@@ -7,28 +8,37 @@ use std::process::Command;
 use super::app_options::AppOptions;
 
 pub trait ProcessExecuter {
-    fn start_program(&self, option: &AppOptions, editor: &str, path: &Path) -> AppResult;
+    /// Starts the given editor with all given paths as arguments and waits until it is closed.
+    /// ## Errors
+    /// - Editor could not be started or did not exit successfully.
+    fn start_program(&self, option: &AppOptions, editor: &str, paths: &[&Path]) -> AppResult;
 }
 
 #[derive(Default)]
 pub struct RealProcessExecuter {}
 
 impl ProcessExecuter for RealProcessExecuter {
-    fn start_program(&self, option: &AppOptions, editor: &str, path: &Path) -> AppResult {
-        let path_as_str = path
-            .to_str()
-            .ok_or_else(|| anyhow!("Could not convert path to a text as argument for editor."))?;
+    fn start_program(&self, option: &AppOptions, editor: &str, paths: &[&Path]) -> AppResult {
+        let paths_as_str = paths
+            .iter()
+            .map(|path| {
+                path.to_str().ok_or_else(|| {
+                    anyhow!("Could not convert path to a text as argument for editor.")
+                })
+            })
+            .collect::<AppResult<Vec<&str>>>()?;
+        let joined = paths_as_str.join(" ");
 
-        debug!("Starting program {} with argument: {}", editor, path_as_str);
+        debug!("Starting program {} with arguments: {}", editor, joined);
 
         if !option.run_editor_dry() {
-            Command::new(editor)
-                .arg(path_as_str)
+            let status = Command::new(editor)
+                .args(&paths_as_str)
                 .spawn()
                 .map_err(AppError::new)
                 .with_context(|| {
                     format!(
-                        "Failded to start editor {0} with args {path_as_str}
+                        "Failded to start editor {0} with args {joined}
 Does {0} as an editor exits and is findable via $PATH ?",
                         editor
                     )
@@ -36,6 +46,9 @@ Does {0} as an editor exits and is findable via $PATH ?",
                 .wait()
                 .map_err(AppError::new)
                 .context("Editor did run correctly")?;
+            if !status.success() {
+                bail!("Editor {} did not exit successfully: {}", editor, status);
+            }
         }
 
         Ok(())
@@ -54,9 +67,12 @@ impl TestProcessExecuter {
 }
 
 impl ProcessExecuter for TestProcessExecuter {
-    fn start_program(&self, _option: &AppOptions, editor: &str, path: &Path) -> AppResult {
+    fn start_program(&self, _option: &AppOptions, editor: &str, paths: &[&Path]) -> AppResult {
         let mut mut_executed_program = self.last_executed_program.borrow_mut();
-        *mut_executed_program = format!("{} {}", editor, path.to_string_lossy());
+        *mut_executed_program = std::iter::once(Cow::Borrowed(editor))
+            .chain(paths.iter().map(|path| path.to_string_lossy()))
+            .collect::<Vec<_>>()
+            .join(" ");
         Ok(())
     }
 }
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+
+use crate::core::app_options::AppOptions;
+use crate::prelude::*;
+
+use super::date_filtering;
+use super::date_models::find_by::{FindByMonthInYear, FindByYearMonthDay};
+use super::dates_names::{yearly_name::YearlyName, DailyName, DateNameForFile, MonthlyName};
+use super::{file_access, journal_store, markdown_sections};
+
+/// Returns a markdown list with a link to every daily journal of the month of the given monthly
+/// journal. Used for the builtin variable MONTH_ROLLUP.
+/// Every entry holds the configured rollup section of the daily journal or its first heading.
+/// ## Errors
+/// - A daily journal could not be read.
+pub fn month_rollup(journal: &MonthlyName, option: &AppOptions) -> AppResult<String> {
+    let (year, month) = journal.year_month();
+    let in_month = FindByYearMonthDay::new(Some(year.into()), Some(month.into()), None)?;
+    let dailies = filter_entries(
+        file_access::fetch_entries_with_archive::<DailyName>(option)?,
+        |names| date_filtering::filter_dailies_by_ymd(names, &in_month),
+    );
+
+    create_rollup(journal, dailies, option, |daily| {
+        NaiveDate::from(daily.date()).to_string()
+    })
+}
+
+/// Returns a markdown list with a link to every monthly journal of the year of the given yearly
+/// journal. Used for the builtin variable YEAR_ROLLUP.
+/// Every entry holds the configured rollup section of the monthly journal or its first heading.
+/// ## Errors
+/// - A monthly journal could not be read.
+pub fn year_rollup(journal: &YearlyName, option: &AppOptions) -> AppResult<String> {
+    let in_year = FindByMonthInYear::InCurrentYear(journal.date_components().year().try_into()?);
+    let monthlies = filter_entries(
+        file_access::fetch_entries_with_archive::<MonthlyName>(option)?,
+        |names| date_filtering::filter_monthly_by_ym(names, &in_year),
+    );
+
+    create_rollup(journal, monthlies, option, |monthly| {
+        let (year, month) = monthly.year_month();
+        format!("{:04}-{:02}", u32::from(year), u32::from(month))
+    })
+}
+
+/// Applies a filter over journal names to the journals together with their paths.
+fn filter_entries<T>(
+    entries: Vec<(T, PathBuf)>,
+    filter: impl FnOnce(Vec<T>) -> Vec<T>,
+) -> Vec<(T, PathBuf)>
+where
+    T: DateNameForFile,
+{
+    let (names, paths): (Vec<T>, Vec<PathBuf>) = entries.into_iter().unzip();
+    let mut path_by_name: HashMap<String, PathBuf> = names
+        .iter()
+        .map(|name| name.name().to_owned())
+        .zip(paths)
+        .collect();
+    filter(names)
+        .into_iter()
+        .filter_map(|name| path_by_name.remove(name.name()).map(|path| (name, path)))
+        .collect()
+}
+
+fn create_rollup<O, T>(
+    own: &O,
+    journals: Vec<(T, PathBuf)>,
+    option: &AppOptions,
+    date_text: impl Fn(&T) -> String,
+) -> AppResult<String>
+where
+    O: DateNameForFile,
+{
+    let own_path = file_access::peek_journal_path(own, option)?;
+    let own_folder = own_path.parent().unwrap_or(Path::new(""));
+    let data_folder = option.get_data_path()?;
+    let section = option
+        .load_config()?
+        .and_then(|config| config.rollup_section().clone());
+
+    let mut entries = Vec::with_capacity(journals.len());
+    for (journal, path) in journals {
+        if let Some(content) = journal_store::read_journal(&path, option)? {
+            let link = markdown_sections::relative_link(&data_folder, own_folder, &path);
+            entries.push(rollup_entry(
+                &date_text(&journal),
+                &link,
+                &content,
+                section.as_deref(),
+            ));
+        }
+    }
+
+    Ok(entries.join("\n"))
+}
+
+/// Returns a list item with a link to a journal.
+/// The content of the given section is indented under the item.
+/// Without a section or if it is empty then the first heading of the journal follows the link.
+fn rollup_entry(date_text: &str, link: &str, content: &str, section: Option<&str>) -> String {
+    let from_section = section
+        .and_then(|heading| markdown_sections::extract_section(content, heading))
+        .filter(|extracted| !extracted.is_empty());
+
+    match from_section {
+        Some(extracted) => {
+            let indented: Vec<String> = extracted
+                .lines()
+                .map(|line| match line.trim_end() {
+                    "" => String::new(),
+                    line => format!("  {line}"),
+                })
+                .collect();
+            format!("- [{date_text}]({link})\n{}", indented.join("\n"))
+        }
+        None => {
+            let excerpt = markdown_sections::first_heading_excerpt(content).unwrap_or("");
+            format!("- [{date_text}]({link}) {excerpt}")
+                .trim_end()
+                .to_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(
+        "Intro\n# Release day\n## Summary\nShipped it",
+        None => "- [2023-03-10](link.md) Release day" ;
+        "Should use first heading without section"
+    )]
+    #[test_case(
+        "# Release day\n## Summary\nShipped it\n\n- party\n## Notes",
+        Some("## Summary") => "- [2023-03-10](link.md)\n  Shipped it\n\n  - party" ;
+        "Should indent content of section"
+    )]
+    #[test_case(
+        "# Release day\n## Notes",
+        Some("## Summary") => "- [2023-03-10](link.md) Release day" ;
+        "Should fall back to first heading without section in journal"
+    )]
+    #[test_case("", None => "- [2023-03-10](link.md)" ; "Should only link empty journal")]
+    fn should_create_rollup_entry(content: &str, section: Option<&str>) -> String {
+        rollup_entry("2023-03-10", "link.md", content, section)
+    }
+}
@@ -0,0 +1,155 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use derive_new::new;
+use serde::Serialize;
+
+use crate::core::app_options::AppOptions;
+use crate::core::date_models::find_by::{FindByRange, FindByYearMonthDay};
+use crate::core::date_models::JournalKind;
+use crate::prelude::*;
+
+use super::{cat_actions, markdown_sections};
+
+#[derive(Debug, PartialEq, Eq, Getters, CopyGetters, Serialize, new)]
+/// Content under a heading of one journal.
+pub struct JournalSection {
+    #[getset(get = "pub")]
+    /// Date of the journal like 2023-03-10 for daily, 2023-03 for monthly and 2023 for yearly
+    /// journals.
+    date: String,
+    #[getset(get_copy = "pub")]
+    kind: JournalKind,
+    #[getset(get = "pub")]
+    path: PathBuf,
+    #[getset(get = "pub")]
+    content: String,
+}
+
+/// Returns the content under the heading with the given title of every journal within the range
+/// in chronological order. A title with marks like "## Blockers" must match the heading exactly.
+/// A title without marks matches a heading of any level regardless of case.
+/// Journals without the heading or with an empty section are left out.
+/// ## Errors
+/// - See [`cat_actions::fetch_journals_in_range`]
+pub fn fetch_sections(
+    title: &str,
+    kinds: &[JournalKind],
+    range: &FindByRange<NaiveDate>,
+    filter: &FindByYearMonthDay,
+    option: &AppOptions,
+) -> AppResult<Vec<JournalSection>> {
+    let journals = cat_actions::fetch_journals_in_range(kinds, range, filter, option)?;
+
+    Ok(journals
+        .into_iter()
+        .filter_map(|journal| {
+            let heading = markdown_sections::find_heading(journal.content(), title)?;
+            let section = markdown_sections::extract_section(journal.content(), heading)?;
+            if section.is_empty() {
+                return None;
+            }
+
+            Some(JournalSection::new(
+                journal.date_text(),
+                journal.kind(),
+                journal.path().clone(),
+                section,
+            ))
+        })
+        .collect())
+}
+
+/// Returns every section under a line with its date. Sections are separated by a blank line.
+pub fn sections_as_text(sections: &[JournalSection]) -> String {
+    sections
+        .iter()
+        .map(|section| format!("{}\n{}\n", section.date, section.content))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns a markdown document with the given title as heading and every section under a
+/// sub heading with its date.
+pub fn sections_as_markdown(title: &str, sections: &[JournalSection]) -> String {
+    let title = match markdown_sections::heading_level(title.trim()) {
+        Some(level) => title.trim()[level..].trim(),
+        None => title.trim(),
+    };
+
+    sections
+        .iter()
+        .fold(format!("# {title}\n"), |document, section| {
+            format!("{document}\n## {}\n\n{}\n", section.date, section.content)
+        })
+}
+
+/// Returns the sections as json array of objects with date, kind, path and content.
+/// ## Errors
+/// - The sections could not be serialized.
+pub fn sections_as_json(sections: &[JournalSection]) -> AppResult<String> {
+    serde_json::to_string_pretty(sections).context("Could not create json of the sections")
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    fn given_sections() -> Vec<JournalSection> {
+        vec![
+            JournalSection::new(
+                "2023-03-10".to_owned(),
+                JournalKind::Daily,
+                PathBuf::from("/data/2023_03_10_daily.md"),
+                "- slow build".to_owned(),
+            ),
+            JournalSection::new(
+                "2023-03".to_owned(),
+                JournalKind::Monthly,
+                PathBuf::from("/data/2023_03_monthly.md"),
+                "- flaky tests\n- review".to_owned(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn should_print_sections_as_text() {
+        let expected = "2023-03-10\n- slow build\n\n2023-03\n- flaky tests\n- review\n";
+
+        assert_eq!(expected, sections_as_text(&given_sections()));
+    }
+
+    #[test]
+    fn should_print_sections_as_markdown() {
+        let expected = "# Blockers\n\n## 2023-03-10\n\n- slow build\n\n## 2023-03\n\n- flaky tests\n- review\n";
+
+        assert_eq!(
+            expected,
+            sections_as_markdown("## Blockers", &given_sections())
+        );
+    }
+
+    #[test]
+    fn should_print_sections_as_json() {
+        let actual: serde_json::Value =
+            serde_json::from_str(&sections_as_json(&given_sections()).unwrap()).unwrap();
+
+        assert_eq!(
+            serde_json::json!([
+                {
+                    "date": "2023-03-10",
+                    "kind": "daily",
+                    "path": "/data/2023_03_10_daily.md",
+                    "content": "- slow build"
+                },
+                {
+                    "date": "2023-03",
+                    "kind": "monthly",
+                    "path": "/data/2023_03_monthly.md",
+                    "content": "- flaky tests\n- review"
+                }
+            ]),
+            actual
+        );
+    }
+}
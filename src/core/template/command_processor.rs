@@ -31,7 +31,7 @@ pub struct CommandToExecute<'a, T> {
 
 impl<'a> CommandToExecute<'a, OsCommandProcossor> {
     pub fn new(provided_command: &'a str) -> Self {
-        Self::init(provided_command, OsCommandProcossor::default())
+        Self::init(provided_command, OsCommandProcossor)
     }
 }
 
@@ -0,0 +1,249 @@
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use is_terminal::IsTerminal;
+
+use crate::core::constants::DEFAULT_PAGER;
+use crate::core::markdown_sections;
+use crate::prelude::*;
+
+const PAGER_ENV: &str = "PAGER";
+const LESS_ENV: &str = "LESS";
+/// Options for less if the user did not set any, the same git uses.
+/// "R" lets the ANSI styling through, "F" quits for content fitting on one screen
+/// and "X" keeps the content on screen after quitting.
+const LESS_DEFAULT_OPTIONS: &str = "FRX";
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const ITALIC: &str = "\x1b[3m";
+const BOLD_UNDERLINED: &str = "\x1b[1;4m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+
+/// Prints the given content of a journal to stdout.
+/// If stdout is a terminal and `raw` is false then the content is rendered as markdown
+/// with ANSI styling and shown via the pager from $PAGER.
+/// Otherwise the content is printed as it is.
+/// ## Errors
+/// - Content could not be written to the pager.
+pub fn show_content(content: &str, raw: bool) -> AppResult {
+    if raw || !io::stdout().is_terminal() {
+        println!("{}", content);
+        return Ok(());
+    }
+
+    let rendered = render_markdown(content);
+    match resolve_pager()? {
+        Some(pager) => show_in_pager(&pager, &rendered),
+        None => {
+            println!("{}", rendered);
+            Ok(())
+        }
+    }
+}
+
+/// Returns the given markdown with ANSI styling for headings, lists, checkboxes, quotes,
+/// emphasis and code. Marks of headings and lists are replaced. Everything else is kept.
+pub fn render_markdown(content: &str) -> String {
+    let mut in_code_block = false;
+
+    content
+        .lines()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+                styled(DIM, line)
+            } else if in_code_block {
+                styled(CYAN, line)
+            } else {
+                render_line(line)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns the program with its arguments from $PAGER or the default pager if $PAGER is not set.
+/// Returns none if $PAGER is set but empty. This turns off paging.
+fn resolve_pager() -> AppResult<Option<Vec<String>>> {
+    let pager = std::env::var(PAGER_ENV).unwrap_or_else(|_| DEFAULT_PAGER.to_owned());
+    let splitted = shellwords::split(&pager)
+        .map_err(AppError::new)
+        .with_context(|| format!("Missing closing quote in pager {}", pager))?;
+
+    Ok(Some(splitted).filter(|parts| !parts.is_empty()))
+}
+
+/// Pipes the given text through the pager.
+/// The text is printed directly if the pager could not be started.
+fn show_in_pager(pager: &[String], text: &str) -> AppResult {
+    let (program, args) = pager
+        .split_first()
+        .expect("Pager was checked to have a program");
+
+    let mut command = Command::new(program);
+    command.args(args).stdin(Stdio::piped());
+    let less_options = std::env::var(LESS_ENV).ok();
+    if needs_less_defaults(pager, less_options.as_deref()) {
+        command.env(LESS_ENV, LESS_DEFAULT_OPTIONS);
+    }
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(error) => {
+            warn!("Could not start pager {}: {}", program, error);
+            println!("{}", text);
+            return Ok(());
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager closes its input if the user quits it before reading everything.
+        if let Err(error) = writeln!(stdin, "{}", text) {
+            if error.kind() != ErrorKind::BrokenPipe {
+                return Err(AppError::new(error).context("Could not write journal to pager"));
+            }
+        }
+    }
+
+    child
+        .wait()
+        .map_err(AppError::new)
+        .context("Pager did not run correctly")?;
+
+    Ok(())
+}
+
+/// Returns true if the pager is less without an option to show ANSI styling
+/// and the user did not set own options for less via $LESS.
+fn needs_less_defaults(pager: &[String], less_options: Option<&str>) -> bool {
+    let (program, args) = match pager.split_first() {
+        Some(split) => split,
+        None => return false,
+    };
+    let is_less = Path::new(program)
+        .file_name()
+        .map_or(false, |name| name == "less");
+    let shows_styling = args.iter().any(|arg| match arg.strip_prefix("--") {
+        Some(long) => long.eq_ignore_ascii_case("raw-control-chars"),
+        None => arg.starts_with('-') && arg.contains(['R', 'r']),
+    });
+
+    is_less && !shows_styling && less_options.is_none()
+}
+
+fn render_line(line: &str) -> String {
+    if let Some(level) = markdown_sections::heading_level(line) {
+        let style = if level == 1 { BOLD_UNDERLINED } else { BOLD };
+        return styled(style, &render_inline(line[level..].trim()));
+    }
+
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    if let Some(quoted) = trimmed.strip_prefix('>') {
+        return format!(
+            "{indent}{DIM}│{RESET} {}",
+            render_inline(quoted.trim_start())
+        );
+    }
+
+    ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| trimmed.strip_prefix(marker))
+        .map_or_else(
+            || render_inline(line),
+            |item| format!("{indent}{}", render_list_item(item)),
+        )
+}
+
+fn render_list_item(item: &str) -> String {
+    if let Some(open) = item.strip_prefix("[ ]") {
+        format!("☐ {}", render_inline(open.trim_start()))
+    } else if let Some(done) = item
+        .strip_prefix("[x]")
+        .or_else(|| item.strip_prefix("[X]"))
+    {
+        format!(
+            "{} {}",
+            styled(GREEN, "☑"),
+            render_inline(done.trim_start())
+        )
+    } else {
+        format!("• {}", render_inline(item))
+    }
+}
+
+fn render_inline(text: &str) -> String {
+    let inline =
+        crate::regex! {r"`([^`]+)`|\*\*(\S|\S.*?\S)\*\*|\*(\S|\S.*?\S)\*|\b_(\S|\S.*?\S)_\b"};
+
+    inline
+        .replace_all(text, |found: &regex::Captures| {
+            if let Some(code) = found.get(1) {
+                styled(CYAN, code.as_str())
+            } else if let Some(strong) = found.get(2) {
+                styled(BOLD, strong.as_str())
+            } else {
+                let emphasized = found.get(3).or_else(|| found.get(4));
+                styled(ITALIC, emphasized.map_or("", |text| text.as_str()))
+            }
+        })
+        .into_owned()
+}
+
+fn styled(style: &str, text: &str) -> String {
+    format!("{style}{text}{RESET}")
+}
+
+#[cfg(test)]
+mod testing {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("# Day" => "\x1b[1;4mDay\x1b[0m" ; "Should underline heading of level 1")]
+    #[test_case("## Log" => "\x1b[1mLog\x1b[0m" ; "Should make other headings bold")]
+    #[test_case("- item" => "• item" ; "Should replace list marker")]
+    #[test_case("  * nested" => "  • nested" ; "Should keep indentation of list")]
+    #[test_case("- [ ] todo" => "☐ todo" ; "Should render open checkbox")]
+    #[test_case("- [x] done" => "\x1b[32m☑\x1b[0m done" ; "Should render checked checkbox")]
+    #[test_case("> quote" => "\x1b[2m│\x1b[0m quote" ; "Should render quote")]
+    #[test_case("1. first" => "1. first" ; "Should keep ordered list")]
+    #[test_case("plain text" => "plain text" ; "Should keep plain text")]
+    fn should_render_line(line: &str) -> String {
+        render_markdown(line)
+    }
+
+    #[test_case("a **strong** word" => "a \x1b[1mstrong\x1b[0m word" ; "Should render strong")]
+    #[test_case("an *emphasized* word" => "an \x1b[3memphasized\x1b[0m word" ; "Should render emphasis with stars")]
+    #[test_case("an _emphasized_ word" => "an \x1b[3memphasized\x1b[0m word" ; "Should render emphasis with underscores")]
+    #[test_case("a `*code*` span" => "a \x1b[36m*code*\x1b[0m span" ; "Should not style within code")]
+    #[test_case("snake_case_name" => "snake_case_name" ; "Should not treat underscores within words as emphasis")]
+    #[test_case("2 * 3 * 4" => "2 * 3 * 4" ; "Should not treat spaced stars as emphasis")]
+    fn should_render_inline(text: &str) -> String {
+        render_inline(text)
+    }
+
+    #[test_case(&["less"], None => true ; "Should set options for less without any")]
+    #[test_case(&["/usr/bin/less", "-i"], None => true ; "Should set options for less in path without raw option")]
+    #[test_case(&["less", "-FRX"], None => false ; "Should keep given raw option")]
+    #[test_case(&["less", "-r"], None => false ; "Should keep given raw control option")]
+    #[test_case(&["less", "--RAW-CONTROL-CHARS"], None => false ; "Should keep given long raw option")]
+    #[test_case(&["less"], Some("-i") => false ; "Should keep options of user for less")]
+    #[test_case(&["more"], None => false ; "Should not set options for other pagers")]
+    fn should_decide_on_less_defaults(pager: &[&str], less_options: Option<&str>) -> bool {
+        let pager: Vec<String> = pager.iter().map(|part| part.to_string()).collect();
+        needs_less_defaults(&pager, less_options)
+    }
+
+    #[test]
+    fn should_render_code_block_without_markdown() {
+        let given = "text\n```\n# no heading\n```\n# Heading";
+        let expected = "text\n\x1b[2m```\x1b[0m\n\x1b[36m# no heading\x1b[0m\n\x1b[2m```\x1b[0m\n\x1b[1;4mHeading\x1b[0m";
+
+        assert_eq!(expected, render_markdown(given));
+    }
+}
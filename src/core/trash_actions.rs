@@ -0,0 +1,371 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+
+use crate::cli::deletion_arguments::CommonDeleteArg;
+use crate::core::app_options::AppOptions;
+use crate::core::constants::{DIGIT_SEP, TRASH_META_EXT};
+use crate::core::file_access;
+use crate::prelude::*;
+
+const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+
+#[derive(Serialize, Deserialize, Debug)]
+/// Saved next to every journal in the trash folder to remember where it came from.
+struct TrashMeta {
+    /// File name of the journal before it was moved into the trash
+    original_name: String,
+    /// Point in time as unix timestamp in seconds when the journal was trashed
+    deleted_at: i64,
+    #[serde(default)]
+    /// Folder of the journal relative to the data folder before it was moved into the trash
+    original_folder: PathBuf,
+}
+
+#[derive(Debug, Getters)]
+/// A journal which was deleted and now resides in the trash folder inside the data folder.
+pub struct TrashedJournal {
+    #[getset(get = "pub")]
+    /// Where the trashed journal is located now.
+    path: PathBuf,
+    meta: TrashMeta,
+}
+
+impl TrashedJournal {
+    pub fn original_name(&self) -> &str {
+        &self.meta.original_name
+    }
+
+    pub fn deleted_at(&self) -> i64 {
+        self.meta.deleted_at
+    }
+
+    /// Returns point of deletion in local time in a human readable format.
+    pub fn deleted_at_as_text(&self) -> String {
+        match Local.timestamp_opt(self.deleted_at(), 0).single() {
+            Some(local) => local.format("%Y-%m-%d %H:%M:%S").to_string(),
+            None => self.deleted_at().to_string(),
+        }
+    }
+
+    fn meta_path(&self) -> PathBuf {
+        create_meta_path(&self.path)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum RestoreResult {
+    /// No journal with the given name is in the trash.
+    NotInTrash,
+    /// There is already a journal at the location to restore to. Nothing was restored.
+    AlreadyExists(PathBuf),
+    /// Journal was moved back to the given location.
+    Restored(PathBuf),
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum PurgeResult {
+    /// User did cancel the purge when asked for confirmation
+    NoConfirmation,
+    /// Number of journals which were removed from the trash for good.
+    Purged(usize),
+    /// Purge was only simulated. Contains names of the journals which would have been removed.
+    DryRun(Vec<String>),
+}
+
+/// Moves the journal at `journal_path` into the trash folder and saves the deletion metadata
+/// next to it.
+/// Returns the location of the journal within the trash folder.
+pub fn move_to_trash(journal_path: &Path, option: &AppOptions) -> AppResult<PathBuf> {
+    let original_name = journal_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow!("Journal at {:?} has no valid file name", journal_path))?
+        .to_owned();
+
+    let trash_folder = file_access::fetch_trash_path(option)?;
+    fs::create_dir_all(&trash_folder)
+        .with_context(|| format!("Could not create trash folder at {:?}", &trash_folder))?;
+    let deleted_at = Local::now().timestamp();
+    let trashed_path = unique_trashed_path(&trash_folder, deleted_at, &original_name)?;
+
+    let data_folder = option.get_data_path()?;
+    let original_folder = journal_path
+        .parent()
+        .and_then(|folder| folder.strip_prefix(&data_folder).ok())
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let meta = TrashMeta {
+        original_name,
+        deleted_at,
+        original_folder,
+    };
+    let meta_content =
+        toml::to_string(&meta).context("Could not create deletion metadata for journal")?;
+
+    fs::rename(journal_path, &trashed_path).with_context(|| {
+        format!(
+            "Could not move journal {:?} into the trash folder",
+            journal_path
+        )
+    })?;
+    if let Err(error) = fs::write(create_meta_path(&trashed_path), meta_content) {
+        // Without its metadata the journal could neither be listed nor restored from the trash.
+        fs::rename(&trashed_path, journal_path).with_context(|| {
+            format!(
+                "Could not save deletion metadata and move journal back from {:?}",
+                &trashed_path
+            )
+        })?;
+        return Err(error).context("Could not save deletion metadata in the trash folder");
+    }
+
+    info!("Moved journal {:?} to {:?}", journal_path, &trashed_path);
+
+    Ok(trashed_path)
+}
+
+/// Returns all journals in the trash. The most recently deleted journals come first.
+/// Returns an empty list if nothing was moved to the trash yet.
+pub fn fetch_trashed_journals(option: &AppOptions) -> AppResult<Vec<TrashedJournal>> {
+    let trash_folder = file_access::fetch_trash_path(option)?;
+    if !trash_folder.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut trashed: Vec<TrashedJournal> = fs::read_dir(&trash_folder)
+        .with_context(|| format!("Could not read trash folder at {:?}", &trash_folder))?
+        .filter_map(|entry| match entry {
+            Ok(resolved) => {
+                let path = resolved.path();
+                let is_meta = path
+                    .extension()
+                    .map(|ext| ext == TRASH_META_EXT)
+                    .unwrap_or(false);
+                if is_meta {
+                    load_trashed_journal(&path)
+                } else {
+                    None
+                }
+            }
+            Err(error) => {
+                warn!(
+                    "Entry could not be read in trash folder {:?}\n. Cause: {error}",
+                    &trash_folder
+                );
+                None
+            }
+        })
+        .collect();
+
+    trashed.sort_by_key(|journal| std::cmp::Reverse(journal.deleted_at()));
+
+    return Ok(trashed);
+
+    fn load_trashed_journal(meta_path: &Path) -> Option<TrashedJournal> {
+        let content = match fs::read_to_string(meta_path) {
+            Ok(content) => content,
+            Err(error) => {
+                warn!(
+                    "Could not read deletion metadata {:?}.\n{}",
+                    meta_path, error
+                );
+                return None;
+            }
+        };
+        let meta: TrashMeta = match toml::from_str(&content) {
+            Ok(meta) => meta,
+            Err(error) => {
+                warn!("Deletion metadata {:?} is invalid.\n{}", meta_path, error);
+                return None;
+            }
+        };
+        let path = meta_path.with_extension("");
+        if path.is_file() {
+            Some(TrashedJournal { path, meta })
+        } else {
+            warn!("No trashed journal found for metadata {:?}", meta_path);
+            None
+        }
+    }
+}
+
+/// Moves the most recently trashed journal with the name `journal_name` back into the data folder.
+pub fn restore_from_trash(journal_name: &str, option: &AppOptions) -> AppResult<RestoreResult> {
+    let to_restore = fetch_trashed_journals(option)?
+        .into_iter()
+        .find(|trashed| trashed.original_name() == journal_name.trim());
+
+    let to_restore = match to_restore {
+        Some(found) => found,
+        None => return Ok(RestoreResult::NotInTrash),
+    };
+
+    let destination_folder = option
+        .get_data_path()?
+        .join(&to_restore.meta.original_folder);
+    let destination = destination_folder.join(to_restore.original_name());
+    if destination.try_exists()? {
+        return Ok(RestoreResult::AlreadyExists(destination));
+    }
+
+    fs::create_dir_all(&destination_folder).with_context(|| {
+        format!(
+            "Could not create folder {:?} to restore journal into",
+            &destination_folder
+        )
+    })?;
+
+    fs::rename(to_restore.path(), &destination).with_context(|| {
+        format!(
+            "Could not move journal {:?} out of the trash folder",
+            to_restore.path()
+        )
+    })?;
+    fs::remove_file(to_restore.meta_path())
+        .context("Could not remove deletion metadata of restored journal")?;
+
+    Ok(RestoreResult::Restored(destination))
+}
+
+/// Removes journals from the trash for good.
+/// If `older_than_days` is none then every journal in the trash is removed.
+pub fn purge_trash(
+    older_than_days: Option<u32>,
+    deletion_option: &CommonDeleteArg,
+    option: &AppOptions,
+    on_confirmation: impl Fn(&[TrashedJournal]) -> AppResult<bool>,
+) -> AppResult<PurgeResult> {
+    let now = Local::now().timestamp();
+    let to_purge: Vec<TrashedJournal> = fetch_trashed_journals(option)?
+        .into_iter()
+        .filter(|trashed| is_older_than(trashed, older_than_days, now))
+        .collect();
+
+    if to_purge.is_empty() {
+        return Ok(PurgeResult::Purged(0));
+    }
+
+    if deletion_option.dry_run() {
+        let names = to_purge
+            .iter()
+            .map(|trashed| trashed.original_name().to_owned())
+            .collect();
+        return Ok(PurgeResult::DryRun(names));
+    }
+
+    let wants_to_purge = if !deletion_option.skip_confirmation() {
+        on_confirmation(&to_purge)?
+    } else {
+        true
+    };
+
+    if !wants_to_purge {
+        return Ok(PurgeResult::NoConfirmation);
+    }
+
+    for trashed in to_purge.iter() {
+        fs::remove_file(trashed.path())?;
+        fs::remove_file(trashed.meta_path())?;
+    }
+
+    Ok(PurgeResult::Purged(to_purge.len()))
+}
+
+/// Returns a path in the trash folder which is neither taken by a journal nor by metadata.
+/// Journals with the same name deleted within the same second get a counter after the
+/// point of deletion.
+fn unique_trashed_path(
+    trash_folder: &Path,
+    deleted_at: i64,
+    original_name: &str,
+) -> AppResult<PathBuf> {
+    let is_taken = |path: &Path| -> AppResult<bool> {
+        Ok(path.try_exists()? || create_meta_path(path).try_exists()?)
+    };
+
+    let first_choice = trash_folder.join(format!("{deleted_at}{DIGIT_SEP}{original_name}"));
+    if !is_taken(&first_choice)? {
+        return Ok(first_choice);
+    }
+
+    for counter in 1.. {
+        let candidate = trash_folder.join(format!(
+            "{deleted_at}{DIGIT_SEP}{counter}{DIGIT_SEP}{original_name}"
+        ));
+        if !is_taken(&candidate)? {
+            return Ok(candidate);
+        }
+    }
+
+    unreachable!("There is always a counter left for a unique name in the trash folder")
+}
+
+fn is_older_than(trashed: &TrashedJournal, older_than_days: Option<u32>, now: i64) -> bool {
+    match older_than_days {
+        Some(days) => now - trashed.deleted_at() > i64::from(days) * SECONDS_PER_DAY,
+        None => true,
+    }
+}
+
+fn create_meta_path(trashed_path: &Path) -> PathBuf {
+    let mut file_name = trashed_path.as_os_str().to_owned();
+    file_name.push(".");
+    file_name.push(TRASH_META_EXT);
+    PathBuf::from(file_name)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use test_case::test_case;
+
+    fn create_trashed(deleted_at: i64) -> TrashedJournal {
+        TrashedJournal {
+            path: PathBuf::from("1_2000_01_01_daily.md"),
+            meta: TrashMeta {
+                original_name: "2000_01_01_daily.md".to_owned(),
+                deleted_at,
+                original_folder: PathBuf::new(),
+            },
+        }
+    }
+
+    #[test_case(None, 100 => true ; "Should purge everything without age")]
+    #[test_case(Some(2), SECONDS_PER_DAY * 3 => true ; "Should purge older than 2 days")]
+    #[test_case(Some(2), SECONDS_PER_DAY => false ; "Should keep younger than 2 days")]
+    fn should_decide_if_older_than(older_than_days: Option<u32>, age: i64) -> bool {
+        const NOW: i64 = 1_000_000;
+        let given = create_trashed(NOW - age);
+        is_older_than(&given, older_than_days, NOW)
+    }
+
+    #[test]
+    fn should_add_counter_to_trashed_path_if_taken() {
+        let trash_folder = tempfile::TempDir::new().unwrap();
+        let taken = trash_folder.path().join("12_2000_01_01_daily.md");
+        fs::write(&taken, "").unwrap();
+        fs::write(
+            create_meta_path(&trash_folder.path().join("12_1_2000_01_01_daily.md")),
+            "",
+        )
+        .unwrap();
+
+        let actual = unique_trashed_path(trash_folder.path(), 12, "2000_01_01_daily.md").unwrap();
+
+        assert_eq!(trash_folder.path().join("12_2_2000_01_01_daily.md"), actual);
+    }
+
+    #[test]
+    fn should_append_meta_ext_to_trashed_path() {
+        let given = Path::new("/data/.trash/12_2000_01_01_daily.md");
+        let actual = create_meta_path(given);
+        assert_eq!(
+            PathBuf::from("/data/.trash/12_2000_01_01_daily.md.toml"),
+            actual
+        );
+    }
+}
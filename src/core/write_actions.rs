@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use date_validation_types::ValidatedDate;
+
+use crate::core::app_options::AppOptions;
+use crate::core::date_models::JournalKind;
+use crate::prelude::*;
+
+use super::dates_names::{
+    with_journal_of_kind, DateNameForFile, InitialabeFromTemplate, ResolvePlaceholders,
+};
+use super::{file_access, journal_store, markdown_sections, open_actions};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// How given content is combined with the content of an existing journal.
+pub enum WriteMode {
+    /// The content of the journal is replaced.
+    #[default]
+    Replace,
+    /// The content is added at the end of the journal.
+    Append,
+    /// The content is added at the start of the journal.
+    Prepend,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WriteResult {
+    Written(PathBuf),
+    /// Journal has content already which would be replaced without force.
+    NotOverwritten(PathBuf),
+}
+
+/// Writes the given content into the journal of the given kind which contains the given date.
+/// A missing journal is created. For appending and prepending it is created from its
+/// template first. A journal with content is only replaced if `force` is true.
+/// ## Errors
+/// - The journal is only found in an archive folder.
+/// - The journal could not be read or written.
+pub fn write_to_journal(
+    date: ValidatedDate,
+    kind: JournalKind,
+    content: &str,
+    mode: WriteMode,
+    force: bool,
+    option: &AppOptions,
+) -> AppResult<WriteResult> {
+    with_journal_of_kind!(kind, date, |journal| write_to(
+        journal, content, mode, force, option
+    ))
+}
+
+fn write_to<T>(
+    journal: T,
+    content: &str,
+    mode: WriteMode,
+    force: bool,
+    option: &AppOptions,
+) -> AppResult<WriteResult>
+where
+    T: DateNameForFile + InitialabeFromTemplate + ResolvePlaceholders,
+{
+    let (path, existing) = find_journal_to_change(&journal, option)?;
+    let new_content = match mode {
+        WriteMode::Replace => {
+            let has_content = existing.map_or(false, |text| !text.trim().is_empty());
+            if has_content && !force {
+                return Ok(WriteResult::NotOverwritten(path));
+            }
+            content.to_owned()
+        }
+        WriteMode::Append => {
+            let existing = existing_or_template(&journal, existing, option)?;
+            markdown_sections::append_at_end(&existing, content.trim_end())
+        }
+        WriteMode::Prepend => {
+            let existing = existing_or_template(&journal, existing, option)?;
+            format!("{}\n{}", content.trim_end(), existing)
+        }
+    };
+
+    journal_store::write_journal(&path, &new_content, option)?;
+
+    Ok(WriteResult::Written(path))
+}
+
+/// Returns the path of the given journal in the data folder together with its content.
+/// The content is none if the journal does not exist yet.
+/// ## Errors
+/// - The journal is only found in an archive folder. Archived journals are never changed.
+pub(crate) fn find_journal_to_change<T>(
+    journal: &T,
+    option: &AppOptions,
+) -> AppResult<(PathBuf, Option<String>)>
+where
+    T: DateNameForFile,
+{
+    if let Some(existing) = file_access::find_existing_journal(journal, option)? {
+        let content = journal_store::read_journal(&existing, option)?;
+        return Ok((existing, content));
+    }
+
+    if let Some(archived) = file_access::find_archived_journal(journal, option)? {
+        bail!(
+            "Journal is archived at {:?}. Restore it via edit with --restore-archived before changing it",
+            archived
+        );
+    }
+    let path = file_access::resolve_journal_path(journal, option)?;
+    info!("No journal created so far at {:?}", &path);
+
+    Ok((path, None))
+}
+
+/// Returns the given content of an existing journal or the content from the template
+/// for a new journal.
+pub(crate) fn existing_or_template<T>(
+    journal: &T,
+    existing: Option<String>,
+    option: &AppOptions,
+) -> AppResult<String>
+where
+    T: InitialabeFromTemplate + ResolvePlaceholders,
+{
+    match existing {
+        Some(content) => Ok(content),
+        None => {
+            Ok(open_actions::initial_content_from_template(journal, option)?.unwrap_or_default())
+        }
+    }
+}
@@ -1,32 +1,50 @@
 #![allow(clippy::uninlined_format_args)]
+use std::io::{ErrorKind, Read, Write};
 use std::process::ExitCode;
 
-use date_validation_types::ValidatedYear;
+use chrono::{Datelike, Local};
+
+use date_validation_types::{ValidatedDate, ValidatedYear};
 use daily_ruster_man::{
     cli::app_args::*,
     core::{
-        list_queries,
+        list_queries, append_actions, cat_actions,
         open_actions::{self, OpenResult},
         process_handling::RealProcessExecuter,
         app_options::AppOptions,
-        date_models::open_by::OpenByMonthInYear,
-        delete_actions::{self, DeletionResult},
+        date_models::{open_by::OpenByMonthInYear, JournalKind, NavigateBy},
+        delete_actions::{self, DeletionResult, RangeDeletionResult},
+        trash_actions::{self, RestoreResult, PurgeResult},
+        terminal_output,
+        migrate_actions::{self, MigrationConflict, MigrationPlan, MigrationResult},
+        doctor_actions, notebook_actions, on_this_day_actions, path_actions, section_actions,
+        write_actions::{self, WriteResult},
+        app_config::{
+            config_check::{self, ConfigCheckResult},
+            config_edit, config_init, config_show,
+        },
     },
+    cli::{prompt, section_command::SectionFormat, trash_command::TrashCommand},
 };
 use daily_ruster_man::prelude::*;
 use env_logger::Env;
 
+/// Exit code of any error. It differs from the exit code 1 of the exists command for a missing
+/// journal so scripts can tell both apart. Clap uses it for invalid arguments too.
+const ERROR_EXIT_CODE: u8 = 2;
+
 fn main() -> ExitCode {
     let cli_args = CliArgs::parse();
 
     init_logger(cli_args.args());
     set_up_env(cli_args.args());
 
-    if let Err(error) = handle_commands(&cli_args) {
-        print_error(&error, cli_args.args());
-        ExitCode::FAILURE
-    } else {
-        ExitCode::SUCCESS
+    match handle_commands(&cli_args) {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            print_error(&error, cli_args.args());
+            ExitCode::from(ERROR_EXIT_CODE)
+        }
     }
 }
 
@@ -47,9 +65,9 @@ fn init_logger(generell_args: &GenerellArgs) {
     env_logger::Builder::from_env(logger_env).init()
 }
 
-fn handle_commands(args: &CliArgs) -> AppResult {
+fn handle_commands(args: &CliArgs) -> AppResult<ExitCode> {
     let app_options = AppOptions::new(args);
-    return match args.commands() {
+    let handled = match args.commands() {
         AppCommands::List(list_queries) => {
             let filter = list_queries.to_date_filter()?;
             let all = list_queries::fetch_all_daily_names(&filter, &app_options)?;
@@ -73,6 +91,33 @@ fn handle_commands(args: &CliArgs) -> AppResult {
         }
         AppCommands::Edit(command_arg) => {
             let edit_query = command_arg.command().to_advance_now()?;
+            let today = Local::now().date_naive().into();
+            if let Some((from, to)) = command_arg.range().to_days(today, edit_query)? {
+                let opened = open_actions::open_days_in_range(
+                    &RealProcessExecuter::default(),
+                    from,
+                    to,
+                    command_arg.existing_only(),
+                    &app_options,
+                    command_arg.option(),
+                    |days| {
+                        prompt::ask_for_confirmation(&format!(
+                            "you want to open {} days and create their missing journals ?",
+                            days
+                        ))
+                    },
+                )?;
+                if opened.is_empty() {
+                    println!("There are no journals to open within the range.");
+                }
+                return Ok(ExitCode::SUCCESS);
+            }
+            let edit_query = match command_arg.navigate().to_navigate_by() {
+                Some(navigate_by) => {
+                    navigate_to_existing(JournalKind::Daily, navigate_by, edit_query, &app_options)?
+                }
+                None => edit_query,
+            };
             let open_result = open_actions::open_by_date(
                 &RealProcessExecuter::default(),
                 edit_query,
@@ -80,10 +125,19 @@ fn handle_commands(args: &CliArgs) -> AppResult {
                 command_arg.option(),
             );
 
-            report_open_result(open_result)
+            report_open_result(open_result, command_arg.option().raw())
         }
         AppCommands::MonthEdit(args) => {
             let month_in_year: OpenByMonthInYear = args.command().to_valid_ym_pair()?;
+            let month_in_year = match args.navigate().to_navigate_by() {
+                Some(navigate_by) => OpenByMonthInYear::from_date(navigate_to_existing(
+                    JournalKind::Monthly,
+                    navigate_by,
+                    month_in_year.first_day()?,
+                    &app_options,
+                )?)?,
+                None => month_in_year,
+            };
             let open_result = open_actions::open_by_month_year(
                 &RealProcessExecuter::default(),
                 month_in_year,
@@ -91,10 +145,25 @@ fn handle_commands(args: &CliArgs) -> AppResult {
                 args.option(),
             );
 
-            report_open_result(open_result)
+            report_open_result(open_result, args.option().raw())
         }
         AppCommands::YearEdit(year_edit) => {
-            let open_result = if let Some(year_given) = year_edit.year() {
+            let navigated = match year_edit.navigate().to_navigate_by() {
+                Some(navigate_by) => {
+                    let year = year_edit
+                        .year()
+                        .unwrap_or_else(|| Local::now().date_naive().year() as u32);
+                    let found = navigate_to_existing(
+                        JournalKind::Yearly,
+                        navigate_by,
+                        ValidatedDate::from_ymd(year, 1, 1)?,
+                        &app_options,
+                    )?;
+                    Some(found.year())
+                }
+                None => year_edit.year(),
+            };
+            let open_result = if let Some(year_given) = navigated {
                 let year_given: ValidatedYear = year_given.try_into()?;
 
                 open_actions::open_by_year(
@@ -111,9 +180,19 @@ fn handle_commands(args: &CliArgs) -> AppResult {
                 )
             };
 
-            report_open_result(open_result)
+            report_open_result(open_result, year_edit.option().raw())
         }
         AppCommands::Delete(to_delete) => {
+            if let Some(range) = to_delete.range().to_range() {
+                let deleted = delete_actions::delete_day_journals_in_range(
+                    &range,
+                    to_delete.common_arg(),
+                    &app_options,
+                )?;
+                report_range_deletion_result(deleted);
+                return Ok(ExitCode::SUCCESS);
+            }
+
             let validated = to_delete.date().to_advance_now()?;
             let has_delteted = delete_actions::delete_day_journal(
                 validated,
@@ -125,6 +204,16 @@ fn handle_commands(args: &CliArgs) -> AppResult {
             Ok(())
         }
         AppCommands::DeleteMonth(to_delete) => {
+            if let Some(range) = to_delete.range().to_range()? {
+                let deleted = delete_actions::delete_month_journals_in_range(
+                    &range,
+                    to_delete.common_arg(),
+                    &app_options,
+                )?;
+                report_range_deletion_result(deleted);
+                return Ok(ExitCode::SUCCESS);
+            }
+
             let validated = to_delete.month().to_valid_ym_pair()?;
             let has_delteted = delete_actions::delete_month_journal(
                 validated,
@@ -136,7 +225,20 @@ fn handle_commands(args: &CliArgs) -> AppResult {
             Ok(())
         }
         AppCommands::DeleteYear(to_delete) => {
-            let validated: ValidatedYear = (*to_delete.year()).try_into()?;
+            if let Some(range) = to_delete.range().to_range()? {
+                let deleted = delete_actions::delete_year_journals_in_range(
+                    range,
+                    to_delete.common_arg(),
+                    &app_options,
+                )?;
+                report_range_deletion_result(deleted);
+                return Ok(ExitCode::SUCCESS);
+            }
+
+            let year = to_delete
+                .year()
+                .context("A year or a range of years must be given")?;
+            let validated: ValidatedYear = year.try_into()?;
             let has_delteted = delete_actions::delete_year_journal(
                 validated,
                 to_delete.common_arg(),
@@ -146,12 +248,63 @@ fn handle_commands(args: &CliArgs) -> AppResult {
             report_deletion_result(has_delteted);
             Ok(())
         }
+        AppCommands::Trash(trash_command) => handle_trash_command(trash_command, &app_options),
+        AppCommands::Migrate(migrate_arg) => handle_migrate_command(migrate_arg, &app_options),
+        AppCommands::Doctor(doctor_arg) => handle_doctor_command(doctor_arg, &app_options),
+        AppCommands::Config(config_command) => handle_config_command(config_command, &app_options),
+        AppCommands::Notebooks => handle_notebooks_command(&app_options),
+        AppCommands::Append(append_arg) => handle_append_command(append_arg, &app_options),
+        AppCommands::Write(write_arg) => handle_write_command(write_arg, &app_options),
+        AppCommands::Cat(cat_arg) => handle_cat_command(cat_arg, &app_options),
+        AppCommands::Section(section_arg) => handle_section_command(section_arg, &app_options),
+        AppCommands::OnThisDay(on_this_day_arg) => {
+            handle_on_this_day_command(on_this_day_arg, &app_options)
+        }
+        AppCommands::Path(path_arg) => {
+            let selection = path_arg.selection();
+            let path = path_actions::journal_path(
+                selection.date().to_advance_now()?,
+                selection.kind().kind(),
+                path_arg.create(),
+                &app_options,
+            )?;
+            println!("{}", path.to_string_lossy());
+            Ok(())
+        }
+        AppCommands::Exists(selection) => {
+            let exists = path_actions::journal_exists(
+                selection.date().to_advance_now()?,
+                selection.kind().kind(),
+                &app_options,
+            )?;
+            return Ok(if exists {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            });
+        }
     };
 
-    fn report_open_result(from_open_action: OpenResult) -> AppResult {
+    return handled.map(|_| ExitCode::SUCCESS);
+
+    fn navigate_to_existing(
+        kind: JournalKind,
+        navigate_by: NavigateBy,
+        from: ValidatedDate,
+        app_options: &AppOptions,
+    ) -> AppResult<ValidatedDate> {
+        list_queries::navigate_to(kind, navigate_by, from, app_options)?.ok_or_else(|| {
+            AppError::msg(format!(
+                "There is no existing {} journal to select in this direction",
+                kind
+            ))
+        })
+    }
+
+    fn report_open_result(from_open_action: OpenResult, raw: bool) -> AppResult {
         let may_content = from_open_action?;
         if let Some(content) = may_content {
-            println!("{}", content);
+            terminal_output::show_content(&content, raw)?;
         }
 
         Ok(())
@@ -165,13 +318,419 @@ fn handle_commands(args: &CliArgs) -> AppResult {
             DeletionResult::NoConfirmation => {
                 println!("Cancaled deletion of journal");
             }
-            DeletionResult::Deleted => {
-                println!("Journal was deleted");
+            DeletionResult::Deleted { trashed_at } => {
+                println!("Journal was moved to the trash at {:?}", trashed_at);
+            }
+            DeletionResult::DryRun(would_delete) => {
+                println!("Would delete journal at {:?}", would_delete);
+            }
+        }
+    }
+
+    fn report_range_deletion_result(deleted: RangeDeletionResult) {
+        match deleted {
+            RangeDeletionResult::NoJournalFound => {
+                println!("There were no journals to be deleted in the given range.");
+            }
+            RangeDeletionResult::NoConfirmation => {
+                println!("Cancaled deletion of journals");
+            }
+            RangeDeletionResult::Deleted(trashed) => {
+                println!("{} journal(s) were moved to the trash", trashed.len());
+            }
+            RangeDeletionResult::DryRun(would_delete) => {
+                println!("Would delete the following journals:");
+                println!("{}", would_delete.join("\n"));
+            }
+        }
+    }
+}
+
+fn handle_trash_command(trash_command: &TrashCommand, app_options: &AppOptions) -> AppResult {
+    match trash_command {
+        TrashCommand::List => {
+            let trashed = trash_actions::fetch_trashed_journals(app_options)?;
+            let lines: Vec<String> = trashed
+                .iter()
+                .map(|journal| {
+                    format!(
+                        "{} deleted at {}",
+                        journal.original_name(),
+                        journal.deleted_at_as_text()
+                    )
+                })
+                .collect();
+            println!("{}", lines.join("\n"));
+        }
+        TrashCommand::Restore(to_restore) => {
+            match trash_actions::restore_from_trash(to_restore.journal(), app_options)? {
+                RestoreResult::NotInTrash => {
+                    println!(
+                        "There is no journal named {} in the trash.",
+                        to_restore.journal()
+                    )
+                }
+                RestoreResult::AlreadyExists(at) => {
+                    println!(
+                        "Journal was not restored. There is already a journal at {:?}",
+                        at
+                    )
+                }
+                RestoreResult::Restored(at) => println!("Journal was restored to {:?}", at),
+            }
+        }
+        TrashCommand::Purge(to_purge) => {
+            let purged = trash_actions::purge_trash(
+                to_purge.older_than(),
+                to_purge.common_arg(),
+                app_options,
+                |journals| {
+                    prompt::ask_for_confirmation(&format!(
+                        "you want to remove {} journal(s) from the trash for good ?",
+                        journals.len()
+                    ))
+                },
+            )?;
+
+            match purged {
+                PurgeResult::NoConfirmation => println!("Cancaled purging of the trash"),
+                PurgeResult::Purged(count) => {
+                    println!("Removed {} journal(s) from the trash", count)
+                }
+                PurgeResult::DryRun(would_purge) => {
+                    println!("Would remove the following journals from the trash:");
+                    println!("{}", would_purge.join("\n"));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_migrate_command(migrate_arg: &MigrateArg, app_options: &AppOptions) -> AppResult {
+    let migrated = migrate_actions::migrate(migrate_arg, app_options, |plan| {
+        print_planned_moves(plan);
+        prompt::ask_for_confirmation(&format!(
+            "you want to move {} journal(s) ?",
+            plan.moves().len()
+        ))
+    })?;
+
+    match migrated {
+        MigrationResult::NoConfirmation => println!("Cancaled migration of journals"),
+        MigrationResult::Conflicts(plan) => {
+            println!("Nothing was moved due to the following conflicts:");
+            for conflict in plan.conflicts() {
+                match conflict {
+                    MigrationConflict::TargetExists(planned) => println!(
+                        "{:?} can not be moved to {:?}. There is already a file",
+                        planned.from(),
+                        planned.to()
+                    ),
+                    MigrationConflict::SameTarget { from, to } => {
+                        println!("{:?} would all be moved to {:?}", from, to)
+                    }
+                }
+            }
+            print_unparseable(&plan);
+        }
+        MigrationResult::DryRun(plan) => {
+            print_planned_moves(&plan);
+            print_unparseable(&plan);
+        }
+        MigrationResult::Migrated(plan) => {
+            println!("Moved {} journal(s)", plan.moves().len());
+            print_unparseable(&plan);
+        }
+    }
+
+    return Ok(());
+
+    fn print_planned_moves(plan: &MigrationPlan) {
+        if plan.moves().is_empty() {
+            println!("All journals are already named and placed as configured.");
+        }
+        for planned in plan.moves() {
+            println!("{:?} -> {:?}", planned.from(), planned.to());
+        }
+    }
+
+    fn print_unparseable(plan: &MigrationPlan) {
+        if !plan.unparseable().is_empty() {
+            println!(
+                "The following files are not recognized as journals and are left as they are:"
+            );
+            for path in plan.unparseable() {
+                println!("{:?}", path);
             }
         }
     }
 }
 
+fn handle_doctor_command(doctor_arg: &DoctorArg, app_options: &AppOptions) -> AppResult {
+    let issues = doctor_actions::diagnose(app_options)?;
+    if issues.is_empty() {
+        println!("No problems found in the data folder.");
+        return Ok(());
+    }
+
+    println!("Found {} problem(s) in the data folder.", issues.len());
+    for issue in issues.iter() {
+        println!("{}", issue.describe());
+        let fix = match issue.describe_fix() {
+            Some(fix) if !doctor_arg.report_only() => fix,
+            _ => continue,
+        };
+
+        if prompt::ask_for_confirmation(&format!("Fix: {} ?", fix))? {
+            match issue.fix(app_options) {
+                Ok(()) => println!("Fixed"),
+                Err(error) => eprintln!("Could not fix problem: {}", error),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_config_command(config_command: &ConfigCommand, app_options: &AppOptions) -> AppResult {
+    match config_command {
+        ConfigCommand::Check => match config_check::check_config_file(app_options)? {
+            ConfigCheckResult::NoConfigFile(path) => {
+                println!("There is no config file at {:?}", path);
+            }
+            ConfigCheckResult::Checked { paths, problems } => {
+                if problems.is_empty() {
+                    for path in paths.iter() {
+                        println!("Config file {:?} is valid", path);
+                    }
+                } else {
+                    println!("Config files have the following problems:");
+                    for problem in problems.iter() {
+                        println!("- {}", problem);
+                    }
+                    return Err(AppError::msg(format!(
+                        "Found {} problem(s) in the config files",
+                        problems.len()
+                    )));
+                }
+            }
+        },
+        ConfigCommand::Init { force } => {
+            let result = config_init::init_config_folder(app_options, *force)?;
+            for written in result.written() {
+                println!("Created {:?}", written);
+            }
+            for skipped in result.skipped() {
+                println!(
+                    "Skipped {:?} because it exists already. Use --force to overwrite it",
+                    skipped
+                );
+            }
+        }
+        ConfigCommand::Show => {
+            for value in config_show::collect_effective_config(app_options)? {
+                println!("{} = {} ({})", value.name(), value.value(), value.source());
+            }
+        }
+        ConfigCommand::Get(get_arg) => match config_edit::get_value(app_options, get_arg.key())? {
+            Some(value) => println!("{}", value),
+            None => {
+                return Err(AppError::msg(format!(
+                    "Option {} is not given in the config file",
+                    get_arg.key()
+                )))
+            }
+        },
+        ConfigCommand::Set(set_arg) => {
+            let path = config_edit::set_value(app_options, set_arg.key(), set_arg.value())?;
+            println!("Set {} in {:?}", set_arg.key(), path);
+        }
+        ConfigCommand::Placeholder(PlaceholderCommand::Add(add_arg)) => {
+            let path = config_edit::add_placeholder(
+                app_options,
+                add_arg.key(),
+                add_arg.value(),
+                add_arg.is_command(),
+            )?;
+            println!("Added placeholder {} to {:?}", add_arg.key(), path);
+        }
+        ConfigCommand::Placeholder(PlaceholderCommand::Remove(remove_arg)) => {
+            let path = config_edit::remove_placeholder(app_options, remove_arg.key())?;
+            println!("Removed placeholder {} from {:?}", remove_arg.key(), path);
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_notebooks_command(app_options: &AppOptions) -> AppResult {
+    let notebooks = notebook_actions::list_notebooks(app_options)?;
+    if notebooks.is_empty() {
+        println!("There are no notebooks in the config file");
+        return Ok(());
+    }
+
+    for notebook in notebooks {
+        let marker = if notebook.is_selected() { "*" } else { " " };
+        println!(
+            "{} {}: {} journal(s) in {:?}",
+            marker,
+            notebook.name(),
+            notebook.journal_count(),
+            notebook.data_path()
+        );
+    }
+
+    Ok(())
+}
+
+fn handle_append_command(append_arg: &AppendArg, app_options: &AppOptions) -> AppResult {
+    let text = match append_arg.message() {
+        Some(message) => message.clone(),
+        None => {
+            let mut from_stdin = String::new();
+            std::io::stdin()
+                .read_to_string(&mut from_stdin)
+                .context("Could not read text to append from stdin")?;
+            from_stdin
+        }
+    };
+    if text.trim().is_empty() {
+        return Err(AppError::msg("There is no text to append"));
+    }
+
+    let entry = if append_arg.timestamp() {
+        append_actions::timestamped(&text, Local::now().time())
+    } else {
+        text
+    };
+    let path = append_actions::append_to_journal(
+        append_arg.date().to_advance_now()?,
+        append_arg.kind().kind(),
+        &entry,
+        append_arg.section().as_deref(),
+        app_options,
+    )?;
+    println!("Appended to {:?}", path);
+
+    Ok(())
+}
+
+fn handle_write_command(write_arg: &WriteArg, app_options: &AppOptions) -> AppResult {
+    let mut content = String::new();
+    std::io::stdin()
+        .read_to_string(&mut content)
+        .context("Could not read journal content from stdin")?;
+
+    let written = write_actions::write_to_journal(
+        write_arg.date().to_advance_now()?,
+        write_arg.kind().kind(),
+        &content,
+        write_arg.mode(),
+        write_arg.force(),
+        app_options,
+    )?;
+
+    match written {
+        WriteResult::Written(path) => println!("Wrote journal {:?}", path),
+        WriteResult::NotOverwritten(path) => {
+            return Err(AppError::msg(format!(
+                "Journal at {:?} has content already. Use --force to replace it",
+                path
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_on_this_day_command(
+    on_this_day_arg: &OnThisDayArg,
+    app_options: &AppOptions,
+) -> AppResult {
+    let date = on_this_day_arg.date().to_advance_now()?;
+    let past_journals =
+        on_this_day_actions::fetch_on_this_day(date, on_this_day_arg.week(), app_options)?;
+    if past_journals.is_empty() {
+        println!("There are no journals of this day in previous years");
+        return Ok(());
+    }
+
+    for past in past_journals {
+        println!(
+            "=== {} ({} year(s) ago) ===",
+            past.date(),
+            past.years_before(date)
+        );
+        println!("{}", past.content().trim_end());
+        println!();
+    }
+
+    Ok(())
+}
+
+fn handle_cat_command(cat_arg: &CatArg, app_options: &AppOptions) -> AppResult {
+    let range = cat_arg.range();
+    let mut journals = cat_actions::fetch_journals_in_range(
+        &range.kind().to_kinds(),
+        &range.to_range(),
+        &range.to_date_filter()?,
+        app_options,
+    )?;
+    if range.reverse() {
+        journals.reverse();
+    }
+    let header_format =
+        cat_actions::resolve_header_format(cat_arg.header().as_deref(), app_options)?;
+
+    let mut stdout = std::io::stdout().lock();
+    for journal in journals {
+        let written = writeln!(
+            stdout,
+            "{}\n{}\n",
+            journal.header(&header_format),
+            journal.content().trim_end()
+        );
+        match written {
+            // A pager was closed before all journals were printed.
+            Err(error) if error.kind() == ErrorKind::BrokenPipe => return Ok(()),
+            written => written?,
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_section_command(section_arg: &SectionArg, app_options: &AppOptions) -> AppResult {
+    let range = section_arg.range();
+    let mut sections = section_actions::fetch_sections(
+        section_arg.heading(),
+        &range.kind().to_kinds(),
+        &range.to_range(),
+        &range.to_date_filter()?,
+        app_options,
+    )?;
+    if range.reverse() {
+        sections.reverse();
+    }
+
+    let output = match section_arg.format() {
+        SectionFormat::Text => section_actions::sections_as_text(&sections),
+        SectionFormat::Json => section_actions::sections_as_json(&sections)?,
+        SectionFormat::Markdown => {
+            section_actions::sections_as_markdown(section_arg.heading(), &sections)
+        }
+    };
+
+    match writeln!(std::io::stdout().lock(), "{}", output.trim_end()) {
+        // A pager was closed before all sections were printed.
+        Err(error) if error.kind() == ErrorKind::BrokenPipe => Ok(()),
+        written => Ok(written?),
+    }
+}
+
 fn print_error(error: &AppError, generell_args: &GenerellArgs) {
     if cfg!(debug_assertions) || generell_args.debug() {
         eprintln!("Error debug: {error:#?}");
@@ -0,0 +1,143 @@
+mod common;
+use std::{fs, path::PathBuf};
+
+use common::{given_date, set_up_options, FileTmpBuilder};
+use daily_ruster_man::core::{append_actions, date_models::JournalKind};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r###"
+daily_template = "+daily.template"
+append_section = "## Log"
+"###;
+const TEMPLATE_CONTENT: &str = "# Day\n\n## Log\n\n## Notes\n";
+
+#[test]
+fn should_create_missing_journal_from_template_and_append_under_section() {
+    let files = create_files_with_config();
+    let app_options = set_up_options(&files);
+
+    let actual = append_actions::append_to_journal(
+        given_date(),
+        JournalKind::Daily,
+        "- first thought",
+        None,
+        &app_options,
+    )
+    .unwrap();
+    append_actions::append_to_journal(
+        given_date(),
+        JournalKind::Daily,
+        "- second thought\n",
+        None,
+        &app_options,
+    )
+    .unwrap();
+
+    assert_eq!(files.path().join("data/2023_04_02_daily.md"), actual);
+    assert_eq!(
+        "# Day\n\n## Log\n- first thought\n- second thought\n\n## Notes\n",
+        fs::read_to_string(actual).unwrap()
+    );
+}
+
+#[test]
+fn should_append_under_given_section_over_configured_one() {
+    let files = create_files_with_config();
+    let app_options = set_up_options(&files);
+
+    let actual = append_actions::append_to_journal(
+        given_date(),
+        JournalKind::Daily,
+        "note",
+        Some("## Notes"),
+        &app_options,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "# Day\n\n## Log\n\n## Notes\nnote\n",
+        fs::read_to_string(actual).unwrap()
+    );
+}
+
+#[test]
+fn should_append_at_end_of_monthly_without_section() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("data/2023_04_monthly.md"),
+            Some("# April".to_owned()),
+        )
+        .build();
+    let app_options = set_up_options(&files);
+
+    let actual = append_actions::append_to_journal(
+        given_date(),
+        JournalKind::Monthly,
+        "end of month",
+        None,
+        &app_options,
+    )
+    .unwrap();
+
+    assert_eq!(files.path().join("data/2023_04_monthly.md"), actual);
+    assert_eq!(
+        "# April\nend of month\n",
+        fs::read_to_string(actual).unwrap()
+    );
+}
+
+#[test]
+fn should_refuse_appending_to_archived_journal() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some("archive_folders = [\"+archive\"]".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("archive/2023_yearly.md"),
+            Some("archived".to_owned()),
+        )
+        .build();
+    let app_options = set_up_options(&files);
+
+    let actual = append_actions::append_to_journal(
+        given_date(),
+        JournalKind::Yearly,
+        "text",
+        None,
+        &app_options,
+    );
+
+    assert!(actual.is_err());
+    assert!(!files.path().join("data/2023_yearly.md").exists());
+}
+
+#[test]
+fn should_refuse_section_which_is_no_heading() {
+    let files = create_files_with_config();
+    let app_options = set_up_options(&files);
+
+    let actual = append_actions::append_to_journal(
+        given_date(),
+        JournalKind::Daily,
+        "note",
+        Some("Log"),
+        &app_options,
+    );
+
+    assert!(actual.is_err());
+    assert!(!files.path().join("data/2023_04_02_daily.md").exists());
+}
+
+fn create_files_with_config() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .with_file(
+            PathBuf::from("daily.template"),
+            Some(TEMPLATE_CONTENT.to_owned()),
+        )
+        .build()
+}
@@ -0,0 +1,140 @@
+mod common;
+use std::{fs, path::PathBuf};
+
+use common::{options_with, FileTmpBuilder};
+use date_validation_types::ValidatedDate;
+use daily_ruster_man::{
+    cli::edit_argument::EditCommonArgs,
+    core::{
+        date_models::find_by::FindByYearMonthDay, list_queries, open_actions,
+        process_handling::TestProcessExecuter,
+    },
+};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r#"
+archive_folders = ["+archive"]
+"#;
+
+#[test]
+fn should_list_archived_journals_marked() {
+    let files = create_active_and_archive();
+    let app_options = options_with(Some(files.path()), Some(&files.path().join("active")));
+    let query = FindByYearMonthDay::new(None, None, None).unwrap();
+
+    let dailies = list_queries::fetch_all_daily_names(&query, &app_options).unwrap();
+    let yearlies = list_queries::fetch_yearly_names(&app_options).unwrap();
+
+    assert_eq!(
+        vec!["2023 03 10".to_owned(), "2022 01 05 (archived)".to_owned()],
+        dailies
+    );
+    assert_eq!(vec!["2021 (archived)".to_owned()], yearlies);
+}
+
+#[test]
+fn should_list_journals_of_archive_within_data_folder_as_archived() {
+    let files = create_active_and_archive();
+    fs::rename(
+        files.path().join("active/2023_03_10_daily.md"),
+        files.path().join("2023_03_10_daily.md"),
+    )
+    .unwrap();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+    let query = FindByYearMonthDay::new(None, None, None).unwrap();
+
+    let dailies = list_queries::fetch_all_daily_names(&query, &app_options).unwrap();
+    let yearlies = list_queries::fetch_yearly_names(&app_options).unwrap();
+
+    assert_eq!(
+        vec!["2023 03 10".to_owned(), "2022 01 05 (archived)".to_owned()],
+        dailies
+    );
+    assert_eq!(vec!["2021 (archived)".to_owned()], yearlies);
+}
+
+#[test]
+fn should_show_archived_journal() {
+    let files = create_active_and_archive();
+    let app_options = options_with(Some(files.path()), Some(&files.path().join("active")));
+    let processor = TestProcessExecuter::default();
+
+    let actual = open_actions::open_by_date(
+        &processor,
+        archived_date(),
+        &app_options,
+        &EditCommonArgs::new(None, true),
+    );
+
+    assert_eq!(Some("archived".to_owned()), actual.unwrap());
+}
+
+#[test]
+fn should_refuse_editing_archived_journal_without_restore() {
+    let files = create_active_and_archive();
+    let app_options = options_with(Some(files.path()), Some(&files.path().join("active")));
+    let processor = TestProcessExecuter::default();
+
+    let actual = open_actions::open_by_date(
+        &processor,
+        archived_date(),
+        &app_options,
+        &EditCommonArgs::default(),
+    );
+
+    assert!(actual.is_err());
+    assert!(processor.get_last_executed_program().is_empty());
+    assert!(!files.path().join("active/2022_01_05_daily.md").exists());
+}
+
+#[test]
+fn should_copy_archived_journal_back_for_editing() {
+    let files = create_active_and_archive();
+    let app_options = options_with(Some(files.path()), Some(&files.path().join("active")));
+    let processor = TestProcessExecuter::default();
+    let mut edit_option = EditCommonArgs::default();
+    edit_option.set_restore_archived(true);
+
+    let actual =
+        open_actions::open_by_date(&processor, archived_date(), &app_options, &edit_option);
+
+    assert!(matches!(actual, Ok(None)));
+    let restored = files.path().join("active/2022_01_05_daily.md");
+    assert_eq!("archived", std::fs::read_to_string(&restored).unwrap());
+    assert!(files.path().join("archive/2022_01_05_daily.md").exists());
+    assert_eq!(
+        format!("vim {}", restored.to_string_lossy()),
+        processor.get_last_executed_program()
+    );
+}
+
+fn archived_date() -> ValidatedDate {
+    ValidatedDate::new(
+        2022.try_into().unwrap(),
+        1.try_into().unwrap(),
+        5.try_into().unwrap(),
+    )
+    .unwrap()
+}
+
+fn create_active_and_archive() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .with_file(
+            PathBuf::from("active/2023_03_10_daily.md"),
+            Some("active".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("archive/2023_03_10_daily.md"),
+            Some("old copy".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("archive/2022_01_05_daily.md"),
+            Some("archived".to_owned()),
+        )
+        .with_file(PathBuf::from("archive/2021_yearly.md"), None)
+        .build()
+}
@@ -0,0 +1,126 @@
+mod common;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use common::{set_up_options, FileTmpBuilder};
+use daily_ruster_man::core::{
+    cat_actions,
+    date_models::{
+        find_by::{FindByRange, FindByYearMonthDay},
+        JournalKind,
+    },
+};
+use tempfile::TempDir;
+
+#[test]
+fn should_fetch_dailies_in_range_in_chronological_order() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+    let range = FindByRange::new(Some(date(2023, 3, 9)), Some(date(2023, 3, 31)));
+
+    let actual = cat_actions::fetch_journals_in_range(
+        &[JournalKind::Daily],
+        &range,
+        &no_filter(),
+        &app_options,
+    )
+    .unwrap();
+
+    let texts: Vec<(String, &str)> = actual
+        .iter()
+        .map(|journal| (journal.date_text(), journal.content().as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("2023-03-09".to_owned(), "ninth"),
+            ("2023-03-10".to_owned(), "tenth"),
+        ],
+        texts
+    );
+}
+
+#[test]
+fn should_fetch_all_kinds_overlapping_with_range() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+    let range = FindByRange::new(Some(date(2023, 3, 10)), None);
+    let kinds = [
+        JournalKind::Yearly,
+        JournalKind::Monthly,
+        JournalKind::Daily,
+    ];
+
+    let actual =
+        cat_actions::fetch_journals_in_range(&kinds, &range, &no_filter(), &app_options).unwrap();
+
+    let headers: Vec<String> = actual
+        .iter()
+        .map(|journal| journal.header("{date} {kind}"))
+        .collect();
+    assert_eq!(
+        vec![
+            "2023 yearly",
+            "2023-03 monthly",
+            "2023-03-10 daily",
+            "2023-04-01 daily"
+        ],
+        headers
+    );
+}
+
+#[test]
+fn should_use_header_from_config() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    let from_config = cat_actions::resolve_header_format(None, &app_options).unwrap();
+    let from_cli = cat_actions::resolve_header_format(Some("{name}"), &app_options).unwrap();
+
+    assert_eq!("## {date}", from_config);
+    assert_eq!("{name}", from_cli);
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn no_filter() -> FindByYearMonthDay {
+    FindByYearMonthDay::new(None, None, None).unwrap()
+}
+
+fn create_files() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some("cat_header = \"## {date}\"".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_03_08_daily.md"),
+            Some("eighth".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_03_09_daily.md"),
+            Some("ninth".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_03_10_daily.md"),
+            Some("tenth".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_04_01_daily.md"),
+            Some("april".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_02_monthly.md"),
+            Some("february".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_03_monthly.md"),
+            Some("march".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_yearly.md"),
+            Some("year".to_owned()),
+        )
+        .build()
+}
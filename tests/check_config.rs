@@ -0,0 +1,126 @@
+mod common;
+use std::path::PathBuf;
+
+use common::{general_with, FileTmpBuilder};
+use daily_ruster_man::core::{
+    app_config::{
+        config_check::{self, ConfigCheckResult, ConfigProblem},
+        config_layers,
+    },
+    app_options::AppOptions,
+};
+use tempfile::TempDir;
+
+const INVALID_CONF_FILE_CONTENT: &str = r#"
+editor = "vim
+"#;
+
+#[test]
+fn should_fail_loading_invalid_config_in_strict_mode() {
+    let files = create_invalid_config();
+    let mut general = general_with(Some(files.path()), Some(files.path()));
+    general.set_strict_config(true);
+    let app_options = AppOptions::with(general);
+
+    let actual = app_options.load_config();
+
+    assert!(actual.is_err());
+}
+
+#[test]
+fn should_fail_loading_config_with_invalid_notebook_in_strict_mode() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some("editor = \"vim\"\n[notebooks.work]\ndaily_extension = \"docx\"".to_owned()),
+        )
+        .build();
+    let mut general = general_with(Some(files.path()), Some(files.path()));
+    general.set_strict_config(true);
+    let app_options = AppOptions::with(general);
+
+    let actual = app_options.load_config();
+
+    assert!(actual.is_err());
+}
+
+#[test]
+fn should_ignore_invalid_config_without_strict_mode() {
+    let files = create_invalid_config();
+    let app_options = AppOptions::with(general_with(Some(files.path()), Some(files.path())));
+
+    let actual = app_options.load_config();
+
+    assert!(matches!(actual, Ok(None)));
+}
+
+#[test]
+fn should_report_syntax_error_of_config_file() {
+    let files = create_invalid_config();
+    let app_options = AppOptions::with(general_with(Some(files.path()), Some(files.path())));
+
+    let actual = config_check::check_config_file(&app_options).expect("Check should not fail");
+
+    match actual {
+        ConfigCheckResult::Checked { paths, problems } => {
+            assert_eq!(vec![files.path().join("config.toml")], paths);
+            assert_eq!(1, problems.len());
+            assert_eq!(&files.path().join("config.toml"), problems[0].path());
+            assert!(matches!(
+                problems[0].problem(),
+                ConfigProblem::Syntax { line: 2, .. }
+            ));
+        }
+        ConfigCheckResult::NoConfigFile(_) => panic!("Config file should have been found"),
+    }
+}
+
+#[test]
+fn should_report_problems_of_every_layer_with_its_file() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("conf/config.toml"),
+            Some("editor = \"vim\"".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("project/.daily_ruster_man.toml"),
+            Some("unknown = 2".to_owned()),
+        )
+        .build();
+    let paths = config_layers::find_layer_files(
+        &files.path().join("conf"),
+        Some(&files.path().join("project")),
+    );
+
+    let actual = config_check::check_files(&paths).unwrap();
+
+    assert_eq!(
+        vec![format!(
+            "{:?}: Unknown key unknown",
+            files.path().join("project/.daily_ruster_man.toml")
+        )],
+        actual
+            .iter()
+            .map(|problem| problem.to_string())
+            .collect::<Vec<String>>()
+    );
+}
+
+#[test]
+fn should_report_missing_config_file() {
+    let files = FileTmpBuilder::default().build();
+    let app_options = AppOptions::with(general_with(Some(files.path()), Some(files.path())));
+
+    let actual = config_check::check_config_file(&app_options).expect("Check should not fail");
+
+    assert!(matches!(actual, ConfigCheckResult::NoConfigFile(_)));
+}
+
+fn create_invalid_config() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(INVALID_CONF_FILE_CONTENT.to_owned()),
+        )
+        .build()
+}
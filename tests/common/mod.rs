@@ -2,8 +2,37 @@
 
 use std::fs::{File, self};
 use std::path::{Path, PathBuf};
+
+use daily_ruster_man::{cli::app_args::GenerellArgs, core::app_options::AppOptions};
+use date_validation_types::ValidatedDate;
 use tempfile::TempDir;
 
+/// Returns options with the config folder at the given temporary folder and the data folder
+/// within its sub folder "data".
+pub fn set_up_options(files: &TempDir) -> AppOptions {
+    options_with(Some(files.path()), Some(&files.path().join("data")))
+}
+
+/// Returns options with the given config folder and data folder.
+pub fn options_with(conf_folder: Option<&Path>, data_folder: Option<&Path>) -> AppOptions {
+    AppOptions::with(general_with(conf_folder, data_folder))
+}
+
+/// Returns CLI arguments with the given config folder and data folder.
+pub fn general_with(conf_folder: Option<&Path>, data_folder: Option<&Path>) -> GenerellArgs {
+    let to_string = |path: &Path| path.to_string_lossy().to_string();
+    GenerellArgs::new(
+        false,
+        conf_folder.map(to_string),
+        data_folder.map(to_string),
+    )
+}
+
+/// Date used by tests which select a journal: 2023-04-02.
+pub fn given_date() -> ValidatedDate {
+    ValidatedDate::from_ymd(2023, 4, 2).unwrap()
+}
+
 pub fn create_sample_data_folder() -> TempDir {
     let to_return = FileTmpBuilder::default()
         .with_file(PathBuf::from("2022_yearly.md"), None)
@@ -48,6 +77,9 @@ impl FileTmpBuilder {
 
         fn create_files(root: &Path, file_name: &Path, maybe_content: Option<String>) {
             let dest_path = root.join(file_name);
+            if let Some(parent) = dest_path.parent() {
+                fs::create_dir_all(parent).expect("Failed to create folder for temp journal file.");
+            }
             _ = File::create(&dest_path)
                 .expect("Failed to create temp journal file for integration test.");
             if let Some(content) = maybe_content {
@@ -0,0 +1,109 @@
+mod common;
+use std::path::PathBuf;
+
+use common::{options_with, FileTmpBuilder};
+use daily_ruster_man::core::app_config::{
+    config_check::{self, ConfigCheckResult},
+    config_init,
+    config_show::{self, EffectiveValue, ValueSource},
+};
+use tempfile::TempDir;
+
+#[test]
+fn should_init_valid_config_with_existing_templates() {
+    let files = TempDir::new().unwrap();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    let actual =
+        config_init::init_config_folder(&app_options, false).expect("Init should not fail");
+
+    assert_eq!(4, actual.written().len());
+    assert!(actual.skipped().is_empty());
+    let check = config_check::check_config_file(&app_options).unwrap();
+    assert!(matches!(check, ConfigCheckResult::Checked { problems, .. } if problems.is_empty()));
+    assert!(files.path().join("daily.template").exists());
+}
+
+#[test]
+fn should_not_overwrite_existing_files_without_force() {
+    const CONF_FILE_CONTENT: &str = "editor = \"nano\"";
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .build();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    let actual =
+        config_init::init_config_folder(&app_options, false).expect("Init should not fail");
+
+    assert_eq!(&vec![files.path().join("config.toml")], actual.skipped());
+    assert_eq!(3, actual.written().len());
+    let content = std::fs::read_to_string(files.path().join("config.toml")).unwrap();
+    assert_eq!(CONF_FILE_CONTENT, content);
+
+    let forced = config_init::init_config_folder(&app_options, true).expect("Init should not fail");
+    assert_eq!(4, forced.written().len());
+}
+
+#[test]
+fn should_show_values_with_their_source() {
+    const CONF_FILE_CONTENT: &str = r#"
+editor = "nano"
+daily_template = "+daily.template"
+[[placeholders]]
+key = "today"
+value = "date"
+is_command = true
+"#;
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .build();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    let actual = config_show::collect_effective_config(&app_options).expect("Show should not fail");
+
+    let root = files.path().to_str().unwrap().to_owned();
+    let daily_template = files.path().join("daily.template");
+    let config_file = files.path().join("config.toml");
+    let expected = vec![
+        EffectiveValue::new("data_path".to_owned(), root.clone(), ValueSource::Cli),
+        EffectiveValue::new("config_path".to_owned(), root, ValueSource::Cli),
+        EffectiveValue::new(
+            "config_file".to_owned(),
+            config_file.to_str().unwrap().to_owned(),
+            ValueSource::File,
+        ),
+        EffectiveValue::new(
+            "notebook".to_owned(),
+            "<not set>".to_owned(),
+            ValueSource::Default,
+        ),
+        EffectiveValue::new("editor".to_owned(), "nano".to_owned(), ValueSource::File),
+        EffectiveValue::new(
+            "daily_template".to_owned(),
+            daily_template.to_str().unwrap().to_owned(),
+            ValueSource::File,
+        ),
+        EffectiveValue::new(
+            "monthly_template".to_owned(),
+            "<not set>".to_owned(),
+            ValueSource::Default,
+        ),
+        EffectiveValue::new(
+            "yearly_template".to_owned(),
+            "<not set>".to_owned(),
+            ValueSource::Default,
+        ),
+        EffectiveValue::new(
+            "placeholders.today".to_owned(),
+            "date (command)".to_owned(),
+            ValueSource::File,
+        ),
+    ];
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,117 @@
+mod common;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use common::{options_with, FileTmpBuilder};
+use daily_ruster_man::{
+    cli::app_args::{CliArgs, Parser},
+    core::app_options::AppOptions,
+};
+use tempfile::TempDir;
+
+const ENV_DATA_PATH: &str = "RUSTER_JOURNAL_DATA_PATH";
+
+/// Tests of this file run in parallel but share the environment variables of the process.
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn should_prefer_cli_over_config_file() {
+    let config_folder = create_config_with_data_folder("/from/config");
+    let data_folder = TempDir::new().unwrap();
+    let app_options = options_with(Some(config_folder.path()), Some(data_folder.path()));
+
+    let actual = app_options.get_data_path().unwrap();
+
+    assert_eq!(data_folder.path(), actual);
+}
+
+#[test]
+fn should_prefer_cli_over_env_and_env_over_config_file() {
+    let config_folder = create_config_with_data_folder("+journals");
+    let from_env = TempDir::new().unwrap();
+    let from_cli = TempDir::new().unwrap();
+    let config_path = config_folder.path().to_str().unwrap();
+
+    let _env = ScopedEnvVar::set(ENV_DATA_PATH, from_env.path());
+    let only_env = CliArgs::try_parse_from(["app", "--config-path", config_path, "year-list"])
+        .expect("Arguments should be valid");
+    let env_and_cli = CliArgs::try_parse_from([
+        "app",
+        "--config-path",
+        config_path,
+        "--data-path",
+        from_cli.path().to_str().unwrap(),
+        "year-list",
+    ])
+    .expect("Arguments should be valid");
+
+    let actual = AppOptions::new(&only_env).get_data_path().unwrap();
+    assert_eq!(from_env.path(), actual);
+    let actual = AppOptions::new(&env_and_cli).get_data_path().unwrap();
+    assert_eq!(from_cli.path(), actual);
+}
+
+#[test]
+fn should_use_data_folder_from_config_file() {
+    let data_folder = TempDir::new().unwrap();
+    let config_folder = create_config_with_data_folder(data_folder.path().to_str().unwrap());
+
+    let actual = options_with(Some(config_folder.path()), None)
+        .get_data_path()
+        .unwrap();
+
+    assert_eq!(data_folder.path(), actual);
+}
+
+#[test]
+fn should_resolve_data_folder_relative_to_config_folder_with_plus() {
+    let config_folder = create_config_with_data_folder("+journals");
+
+    let actual = options_with(Some(config_folder.path()), None)
+        .get_data_path()
+        .unwrap();
+
+    assert_eq!(config_folder.path().join("journals"), actual);
+}
+
+#[test]
+fn should_fall_back_to_default_without_any_source() {
+    let empty_config_folder = TempDir::new().unwrap();
+
+    let actual = options_with(Some(empty_config_folder.path()), None)
+        .get_data_path()
+        .unwrap();
+
+    let dev_data_folder = Path::new(env!("CARGO_MANIFEST_DIR")).join(".dev_data/share");
+    assert_eq!(dev_data_folder, actual);
+}
+
+fn create_config_with_data_folder(data_folder: &str) -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(format!("data_foler = {:?}", data_folder)),
+        )
+        .build()
+}
+
+/// Sets an environment variable until it is dropped.
+/// Holds a lock meanwhile so no other test of this file changes the environment at the same time.
+struct ScopedEnvVar {
+    name: &'static str,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl ScopedEnvVar {
+    fn set(name: &'static str, value: &Path) -> Self {
+        let lock = ENV_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        std::env::set_var(name, value);
+        Self { name, _lock: lock }
+    }
+}
+
+impl Drop for ScopedEnvVar {
+    fn drop(&mut self) {
+        std::env::remove_var(self.name);
+    }
+}
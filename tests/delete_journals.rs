@@ -4,7 +4,8 @@ use std::path::PathBuf;
 use daily_ruster_man::AppResult;
 use daily_ruster_man::core::app_options::AppOptions;
 use daily_ruster_man::core::date_models::open_by::OpenByMonthInYear;
-use daily_ruster_man::core::delete_actions::{self, DeletionResult};
+use daily_ruster_man::core::date_models::find_by::FindByRange;
+use daily_ruster_man::core::delete_actions::{self, DeletionResult, RangeDeletionResult};
 use daily_ruster_man::cli::{app_args::GenerellArgs, deletion_arguments::CommonDeleteArg};
 
 use date_validation_types::{ValidatedYear, ValidatedMonth, ValidatedDate, ValidatedDay};
@@ -120,7 +121,7 @@ fn set_up(to_delete: &str, should_exit_before: bool) -> DeletionSetup {
         );
     }
 
-    let common = CommonDeleteArg::new(true);
+    let common = CommonDeleteArg::new(true, false);
     let general = GenerellArgs::new(
         false,
         None,
@@ -137,8 +138,11 @@ fn set_up(to_delete: &str, should_exit_before: bool) -> DeletionSetup {
 
 fn assert_deletion(result: AppResult<DeletionResult>, provided_set_up: DeletionSetup) {
     // Assert
-    if let Ok(deletion_outcome) = result {
-        assert!(DeletionResult::Deleted == deletion_outcome);
+    if let Ok(DeletionResult::Deleted { trashed_at }) = result {
+        assert!(
+            trashed_at.exists(),
+            "Deleted journal was not moved to trash."
+        );
     } else {
         panic!("Should have returned that the journal was deleted.");
     }
@@ -162,3 +166,51 @@ struct DeletionSetup {
     pub general: GenerellArgs,
     pub path_to_delete: PathBuf,
 }
+
+#[test]
+fn should_delete_daily_journals_in_range() {
+    let provided_set_up = set_up("2023_03_09_daily.md", true);
+    let range = FindByRange::new(
+        Some(ValidatedDate::from_ymd(2023, 2, 1).unwrap()),
+        Some(ValidatedDate::from_ymd(2023, 3, 8).unwrap()),
+    );
+
+    // Act
+    let result = delete_actions::delete_day_journals_in_range(
+        &range,
+        &provided_set_up.common,
+        &AppOptions::with(provided_set_up.general.clone()),
+    );
+
+    // Assert
+    let data_folder = provided_set_up._files.path();
+    assert!(matches!(result, Ok(RangeDeletionResult::Deleted(trashed)) if trashed.len() == 2));
+    assert!(!data_folder.join("2023_02_02_daily.md").exists());
+    assert!(!data_folder.join("2023_03_08_daily.md").exists());
+    assert!(provided_set_up.path_to_delete.exists());
+}
+
+#[test]
+fn should_only_list_monthly_journals_in_range_for_dry_run() {
+    let provided_set_up = set_up("2002_02_monthly.md", true);
+    let year: ValidatedYear = 2002.try_into().unwrap();
+    let range = FindByRange::new(
+        Some((year, 1.try_into().unwrap())),
+        Some((year, 12.try_into().unwrap())),
+    );
+
+    // Act
+    let result = delete_actions::delete_month_journals_in_range(
+        &range,
+        &CommonDeleteArg::new(true, true),
+        &AppOptions::with(provided_set_up.general.clone()),
+    );
+
+    // Assert
+    let expected = vec![
+        "2002_02_monthly.md".to_owned(),
+        "2002_11_monthly.md".to_owned(),
+    ];
+    assert!(matches!(result, Ok(RangeDeletionResult::DryRun(names)) if names == expected));
+    assert!(provided_set_up.path_to_delete.exists());
+}
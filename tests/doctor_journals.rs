@@ -0,0 +1,113 @@
+mod common;
+use std::path::PathBuf;
+
+use common::{options_with, FileTmpBuilder};
+use daily_ruster_man::{
+    cli::app_args::GenerellArgs,
+    core::{
+        app_options::AppOptions,
+        doctor_actions::{self, HealthIssue},
+        trash_actions,
+    },
+};
+
+#[test]
+fn should_report_problems_of_data_folder() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("2023_03_10_daily.md"),
+            Some("fine".to_owned()),
+        )
+        .with_file(PathBuf::from("2023_3_8_daily.md"), Some("near".to_owned()))
+        .with_file(PathBuf::from("2023_02_30_daily.md"), Some("no".to_owned()))
+        .with_file(PathBuf::from("2022_yearly.md"), None)
+        .with_file(PathBuf::from("notes.txt"), Some("notes".to_owned()))
+        .build();
+    let app_options = options_with(None, Some(files.path()));
+    let root = files.path();
+
+    let mut actual = doctor_actions::diagnose(&app_options).expect("Diagnose should not fail");
+    actual.sort_by_key(|issue| issue.describe());
+
+    let mut expected = vec![
+        HealthIssue::NearMiss {
+            path: root.join("2023_3_8_daily.md"),
+            suggested: root.join("2023_03_08_daily.md"),
+        },
+        HealthIssue::InvalidDate(root.join("2023_02_30_daily.md")),
+        HealthIssue::Empty(root.join("2022_yearly.md")),
+        HealthIssue::Unrecognized(root.join("notes.txt")),
+    ];
+    expected.sort_by_key(|issue| issue.describe());
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn should_report_journals_differing_only_by_extension() {
+    let files = FileTmpBuilder::default()
+        .with_file(PathBuf::from("2023_03_09_daily.md"), Some("md".to_owned()))
+        .with_file(
+            PathBuf::from("2023_03_09_daily.org"),
+            Some("org".to_owned()),
+        )
+        .build();
+    let app_options = options_with(None, Some(files.path()));
+
+    let actual = doctor_actions::diagnose(&app_options).expect("Diagnose should not fail");
+
+    match actual.as_slice() {
+        [HealthIssue::Duplicates { keep, others }] => {
+            let mut all = others.clone();
+            all.push(keep.clone());
+            all.sort();
+            assert_eq!(
+                vec![
+                    files.path().join("2023_03_09_daily.md"),
+                    files.path().join("2023_03_09_daily.org")
+                ],
+                all
+            );
+        }
+        _ => panic!("Expected only duplicates to be reported, got {:?}", actual),
+    }
+}
+
+#[test]
+fn should_fix_near_miss_and_move_unrecognized_to_trash() {
+    let files = FileTmpBuilder::default()
+        .with_file(PathBuf::from("2023_3_8_daily.md"), Some("near".to_owned()))
+        .with_file(PathBuf::from("notes.txt"), Some("notes".to_owned()))
+        .build();
+    let app_options = options_with(None, Some(files.path()));
+
+    for issue in doctor_actions::diagnose(&app_options).expect("Diagnose should not fail") {
+        issue.fix(&app_options).expect("Fix should not fail");
+    }
+
+    assert!(files.path().join("2023_03_08_daily.md").exists());
+    assert!(!files.path().join("notes.txt").exists());
+    let trashed = trash_actions::fetch_trashed_journals(&app_options).unwrap();
+    assert_eq!("notes.txt", trashed[0].original_name());
+    let after_fix = doctor_actions::diagnose(&app_options).expect("Diagnose should not fail");
+    assert!(after_fix.is_empty());
+}
+
+#[test]
+fn should_not_report_config_file_in_data_folder() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some("editor = \"vim\"".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("2023_03_09_daily.md"),
+            Some("fine".to_owned()),
+        )
+        .build();
+    let path = files.path().to_str().unwrap().to_string();
+    let app_options = AppOptions::with(GenerellArgs::new(false, Some(path.clone()), Some(path)));
+
+    let actual = doctor_actions::diagnose(&app_options).expect("Diagnose should not fail");
+
+    assert!(actual.is_empty());
+}
@@ -0,0 +1,111 @@
+mod common;
+use std::path::PathBuf;
+
+use common::{options_with, FileTmpBuilder};
+use daily_ruster_man::core::app_config::{config_edit, config_init, config_show};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r#"# Favorite editor
+editor = "vim"
+
+# Encryption
+[encryption]
+passphrase_env = "SECRET"
+"#;
+
+#[test]
+fn should_set_value_and_keep_comments() {
+    let files = TempDir::new().unwrap();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+    config_init::init_config_folder(&app_options, false).unwrap();
+    let before = read_config(&files);
+
+    config_edit::set_value(&app_options, "editor", "nano").expect("Set should not fail");
+
+    let after = read_config(&files);
+    assert_eq!(before, after.replace("editor = \"nano\"\n", ""));
+    assert_eq!(
+        Some("nano".to_owned()),
+        config_edit::get_value(&app_options, "editor").unwrap()
+    );
+    let reloaded = options_with(Some(files.path()), Some(files.path()));
+    let config = reloaded.load_config().unwrap().unwrap();
+    assert_eq!(&Some("nano".to_owned()), config.editor());
+}
+
+#[test]
+fn should_set_and_get_value_within_table() {
+    let files = create_config();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    config_edit::set_value(&app_options, "encryption.passphrase_env", "OTHER")
+        .expect("Set should not fail");
+
+    assert_eq!(
+        Some("OTHER".to_owned()),
+        config_edit::get_value(&app_options, "encryption.passphrase_env").unwrap()
+    );
+    let content = read_config(&files);
+    assert!(content.contains("# Favorite editor\neditor = \"vim\""));
+    assert!(content.contains("# Encryption\n[encryption]"));
+    assert_eq!(
+        None,
+        config_edit::get_value(&app_options, "encryption.key_file").unwrap()
+    );
+}
+
+#[test]
+fn should_not_write_invalid_value() {
+    let files = create_config();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    assert!(config_edit::set_value(&app_options, "daily_extension", "docx").is_err());
+    assert!(config_edit::set_value(&app_options, "editr", "nano").is_err());
+    assert_eq!(CONF_FILE_CONTENT, read_config(&files));
+}
+
+#[test]
+fn should_add_replace_and_remove_placeholders() {
+    let files = create_config();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    config_edit::add_placeholder(&app_options, "today", "date", true).unwrap();
+    config_edit::add_placeholder(&app_options, "hello", "world", false).unwrap();
+    config_edit::add_placeholder(&app_options, "today", "now", false).unwrap();
+
+    let shown = config_show::collect_effective_config(&options_with(
+        Some(files.path()),
+        Some(files.path()),
+    ))
+    .unwrap();
+    let placeholders: Vec<(&str, &str)> = shown
+        .iter()
+        .filter(|shown_value| shown_value.name().starts_with("placeholders."))
+        .map(|shown_value| (shown_value.name().as_str(), shown_value.value().as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("placeholders.today", "now"),
+            ("placeholders.hello", "world")
+        ],
+        placeholders
+    );
+
+    config_edit::remove_placeholder(&app_options, "today").unwrap();
+    config_edit::remove_placeholder(&app_options, "hello").unwrap();
+    assert!(config_edit::remove_placeholder(&app_options, "hello").is_err());
+    assert!(!read_config(&files).contains("placeholders"));
+}
+
+fn create_config() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .build()
+}
+
+fn read_config(files: &TempDir) -> String {
+    std::fs::read_to_string(files.path().join("config.toml")).unwrap()
+}
@@ -0,0 +1,79 @@
+mod common;
+use std::{fs, path::PathBuf};
+
+use common::{given_date, set_up_options, FileTmpBuilder};
+use daily_ruster_man::core::{date_models::JournalKind, path_actions};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r#"
+data_layout = "{year}"
+monthly_template = "+monthly.template"
+"#;
+
+#[test]
+fn should_print_path_of_missing_journal_without_creating_it() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    let actual =
+        path_actions::journal_path(given_date(), JournalKind::Monthly, false, &app_options)
+            .unwrap();
+
+    assert_eq!(files.path().join("data/2023/2023_04_monthly.md"), actual);
+    assert!(actual.is_absolute());
+    assert!(!files.path().join("data/2023").exists());
+}
+
+#[test]
+fn should_create_missing_journal_from_template() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    let actual =
+        path_actions::journal_path(given_date(), JournalKind::Monthly, true, &app_options).unwrap();
+
+    assert_eq!("# April\n", fs::read_to_string(&actual).unwrap());
+    assert!(
+        path_actions::journal_exists(given_date(), JournalKind::Monthly, &app_options).unwrap()
+    );
+}
+
+#[test]
+fn should_find_existing_journal_in_other_folder() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    let actual =
+        path_actions::journal_path(given_date(), JournalKind::Daily, true, &app_options).unwrap();
+
+    assert_eq!(files.path().join("data/2023_04_02_daily.md"), actual);
+    assert_eq!("existing", fs::read_to_string(&actual).unwrap());
+}
+
+#[test]
+fn should_tell_if_journal_exists() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    assert!(path_actions::journal_exists(given_date(), JournalKind::Daily, &app_options).unwrap());
+    assert!(
+        !path_actions::journal_exists(given_date(), JournalKind::Yearly, &app_options).unwrap()
+    );
+}
+
+fn create_files() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .with_file(
+            PathBuf::from("monthly.template"),
+            Some("# April\n".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_04_02_daily.md"),
+            Some("existing".to_owned()),
+        )
+        .build()
+}
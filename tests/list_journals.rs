@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod common;
+use std::path::PathBuf;
+
 use date_validation_types::{ValidatedYear, ValidatedMonth};
 use daily_ruster_man::{
     core::{
@@ -107,6 +109,54 @@ fn should_list_exact_monthly_journals() {
     insta::assert_yaml_snapshot!(all_monthly_journals);
 }
 
+#[test]
+fn should_list_daily_journals_in_sub_folders() {
+    let files = common::FileTmpBuilder::default()
+        .with_file(PathBuf::from("2023/03/2023_03_09_daily.md"), None)
+        .with_file(PathBuf::from("2023/2023_03_08_daily.md"), None)
+        .with_file(PathBuf::from("2023_02_02_daily.md"), None)
+        .with_file(PathBuf::from(".trash/1_1999_01_21_daily.md"), None)
+        .build();
+    let app_options = AppOptions::with(GenerellArgs::new(
+        false,
+        None,
+        Some(files.path().to_str().unwrap().to_string()),
+    ));
+    let querry =
+        FindByYearMonthDay::new(None, None, None).expect("Invalid date for querry provided");
+
+    let actual = list_queries::fetch_all_daily_names(&querry, &app_options)
+        .expect("Could not fetch all daily journals");
+
+    assert_eq!(vec!["2023 03 09", "2023 03 08", "2023 02 02"], actual);
+}
+
+#[test]
+fn should_list_daily_journals_with_configured_naming_pattern() {
+    const CONF_FILE_CONTENT: &str = r#"
+      daily_name_pattern = "{yyyy}-{mm}-{dd}.md"
+        "#;
+    let files = common::FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .with_file(PathBuf::from("2023-03-09.md"), None)
+        .with_file(PathBuf::from("2023_03_08_daily.md"), None)
+        .with_file(PathBuf::from("2023-03-07.org"), None)
+        .with_file(PathBuf::from("Ideas.md"), None)
+        .build();
+    let path = files.path().to_str().unwrap().to_string();
+    let app_options = AppOptions::with(GenerellArgs::new(false, Some(path.clone()), Some(path)));
+    let querry =
+        FindByYearMonthDay::new(None, None, None).expect("Invalid date for querry provided");
+
+    let actual = list_queries::fetch_all_daily_names(&querry, &app_options)
+        .expect("Could not fetch all daily journals");
+
+    assert_eq!(vec!["2023 03 09", "2023 03 08"], actual);
+}
+
 struct SetUpForListingQuerry {
     _files: TempDir,
     app_options: AppOptions,
@@ -0,0 +1,130 @@
+mod common;
+use std::path::PathBuf;
+
+use common::{options_with, FileTmpBuilder};
+use daily_ruster_man::{
+    cli::migrate_command::MigrateArg,
+    core::migrate_actions::{self, MigrationConflict, MigrationResult},
+};
+
+const CONF_WITH_PATTERN_AND_LAYOUT: &str = r#"
+  daily_name_pattern = "{yyyy}-{mm}-{dd}.{ext}"
+  data_layout = "{year}"
+    "#;
+
+#[test]
+fn should_migrate_to_configured_pattern_and_layout() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_WITH_PATTERN_AND_LAYOUT.to_owned()),
+        )
+        .with_file(
+            PathBuf::from("2023_03_09_daily.md"),
+            Some("ninth".to_owned()),
+        )
+        .with_file(PathBuf::from("2023_03_08_daily.org"), None)
+        .with_file(PathBuf::from("2022_yearly.md"), None)
+        .build();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    let actual = migrate_actions::migrate(&migrate_arg(false), &app_options, |_| Ok(true))
+        .expect("Migration should not fail");
+
+    match actual {
+        MigrationResult::Migrated(plan) => {
+            assert_eq!(3, plan.moves().len());
+            assert_eq!(&vec![files.path().join("config.toml")], plan.unparseable());
+        }
+        _ => panic!("Journals should have been migrated"),
+    }
+    let moved_daily = files.path().join("2023/2023-03-09.md");
+    assert_eq!("ninth", std::fs::read_to_string(moved_daily).unwrap());
+    assert!(files.path().join("2023/2023-03-08.org").exists());
+    assert!(files.path().join("2022/2022_yearly.md").exists());
+    assert!(!files.path().join("2023_03_09_daily.md").exists());
+}
+
+#[test]
+fn should_only_plan_migration_for_dry_run() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_WITH_PATTERN_AND_LAYOUT.to_owned()),
+        )
+        .with_file(PathBuf::from("2023_03_09_daily.md"), None)
+        .build();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    let actual = migrate_actions::migrate(&migrate_arg(true), &app_options, |_| {
+        panic!("Dry run should not ask for confirmation")
+    })
+    .expect("Migration should not fail");
+
+    assert!(matches!(actual, MigrationResult::DryRun(plan) if plan.moves().len() == 1));
+    assert!(files.path().join("2023_03_09_daily.md").exists());
+}
+
+#[test]
+fn should_not_move_anything_on_conflicts() {
+    const CONF_FILE_CONTENT: &str = r#"
+      daily_name_pattern = "{yyyy}-{mm}-{dd}.md"
+        "#;
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .with_file(PathBuf::from("2023_03_09_daily.md"), None)
+        .with_file(PathBuf::from("2023_03_09_daily.org"), None)
+        .with_file(PathBuf::from("2023_03_08_daily.md"), None)
+        .build();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+
+    let actual = migrate_actions::migrate(&migrate_arg(false), &app_options, |_| Ok(true))
+        .expect("Migration should not fail");
+
+    match actual {
+        MigrationResult::Conflicts(plan) => {
+            let expected = MigrationConflict::SameTarget {
+                from: vec![
+                    files.path().join("2023_03_09_daily.md"),
+                    files.path().join("2023_03_09_daily.org"),
+                ],
+                to: files.path().join("2023-03-09.md"),
+            };
+            assert_eq!(&vec![expected], plan.conflicts());
+        }
+        _ => panic!("Migration should have been stopped by conflicts"),
+    }
+    assert!(files.path().join("2023_03_08_daily.md").exists());
+}
+
+#[test]
+fn should_migrate_from_given_pattern_and_normalize_year_padding() {
+    let files = FileTmpBuilder::default()
+        .with_file(PathBuf::from("2023-03-09.md"), None)
+        .with_file(PathBuf::from("812_01_02_daily.md"), None)
+        .with_file(PathBuf::from("0812_01_monthly.md"), None)
+        .build();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+    let migrate_arg = MigrateArg::new(
+        Some("{yyyy}-{mm}-{dd}.{ext}".to_owned()),
+        None,
+        None,
+        true,
+        false,
+    );
+
+    let actual = migrate_actions::migrate(&migrate_arg, &app_options, |_| Ok(true))
+        .expect("Migration should not fail");
+
+    assert!(matches!(actual, MigrationResult::Migrated(plan) if plan.moves().len() == 2));
+    assert!(files.path().join("2023_03_09_daily.md").exists());
+    assert!(files.path().join("0812_01_02_daily.md").exists());
+    assert!(files.path().join("0812_01_monthly.md").exists());
+}
+
+fn migrate_arg(dry_run: bool) -> MigrateArg {
+    MigrateArg::new(None, None, None, true, dry_run)
+}
@@ -0,0 +1,37 @@
+mod common;
+use date_validation_types::ValidatedDate;
+use daily_ruster_man::{
+    cli::app_args::GenerellArgs,
+    core::{
+        app_options::AppOptions,
+        date_models::{JournalKind, NavigateBy},
+        list_queries,
+    },
+};
+use test_case::test_case;
+
+#[test_case(JournalKind::Daily, NavigateBy::Last, (2000, 1, 1) => Some((2023, 3, 9)) ; "Should select most recent daily")]
+#[test_case(JournalKind::Daily, NavigateBy::Previous, (2023, 3, 8) => Some((2023, 2, 2)) ; "Should select previous daily")]
+#[test_case(JournalKind::Daily, NavigateBy::Next, (1999, 1, 22) => Some((1999, 2, 21)) ; "Should select next daily")]
+#[test_case(JournalKind::Daily, NavigateBy::Next, (2023, 3, 9) => None ; "Should select no daily after newest one")]
+#[test_case(JournalKind::Monthly, NavigateBy::Previous, (2002, 11, 20) => Some((2002, 2, 1)) ; "Should select previous monthly")]
+#[test_case(JournalKind::Monthly, NavigateBy::Last, (1990, 1, 1) => Some((2002, 11, 1)) ; "Should select most recent monthly")]
+#[test_case(JournalKind::Yearly, NavigateBy::Next, (2010, 6, 6) => Some((2022, 1, 1)) ; "Should select next yearly")]
+#[test_case(JournalKind::Yearly, NavigateBy::Previous, (2022, 6, 6) => None ; "Should select no yearly before oldest one")]
+fn should_navigate_to_existing_journal(
+    kind: JournalKind,
+    navigate_by: NavigateBy,
+    (year, month, day): (u32, u32, u32),
+) -> Option<(u32, u32, u32)> {
+    let files = common::create_sample_data_folder();
+    let app_options = AppOptions::with(GenerellArgs::new(
+        false,
+        None,
+        Some(files.path().to_str().unwrap().to_string()),
+    ));
+    let from = ValidatedDate::from_ymd(year, month, day).unwrap();
+
+    list_queries::navigate_to(kind, navigate_by, from, &app_options)
+        .unwrap()
+        .map(|found| (found.year(), found.month(), found.day()))
+}
@@ -0,0 +1,146 @@
+mod common;
+use std::path::PathBuf;
+
+use common::{general_with, FileTmpBuilder};
+use daily_ruster_man::{
+    cli::app_args::GenerellArgs,
+    core::{app_options::AppOptions, notebook_actions},
+};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r#"
+editor = "vim"
+default_notebook = "personal"
+
+[notebooks.work]
+data_foler = "+work"
+editor = "nano"
+
+[notebooks.personal]
+data_foler = "+personal"
+"#;
+
+#[test]
+fn should_use_default_notebook_without_selection() {
+    let files = create_notebooks();
+    let app_options = AppOptions::with(general_with(Some(files.path()), None));
+
+    let actual = app_options.get_data_path().unwrap();
+
+    assert_eq!(files.path().join("personal"), actual);
+    let config = app_options.load_config().unwrap().unwrap();
+    assert_eq!(&Some("vim".to_owned()), config.editor());
+}
+
+#[test]
+fn should_use_options_of_selected_notebook() {
+    let files = create_notebooks();
+    let mut general = general_with(Some(files.path()), None);
+    general.set_notebook(Some("work".to_owned()));
+    let app_options = AppOptions::with(general);
+
+    let actual = app_options.get_data_path().unwrap();
+
+    assert_eq!(files.path().join("work"), actual);
+    let config = app_options.load_config().unwrap().unwrap();
+    assert_eq!(&Some("nano".to_owned()), config.editor());
+}
+
+#[test]
+fn should_fail_for_unknown_notebook() {
+    let files = create_notebooks();
+    let mut general = general_with(Some(files.path()), None);
+    general.set_notebook(Some("unknown".to_owned()));
+    let app_options = AppOptions::with(general);
+
+    assert!(app_options.load_config().is_err());
+}
+
+#[test]
+fn should_list_notebooks_with_journal_counts() {
+    let files = create_notebooks();
+    let app_options = AppOptions::with(general_with(Some(files.path()), None));
+
+    let actual: Vec<(String, PathBuf, usize, bool)> =
+        notebook_actions::list_notebooks(&app_options)
+            .unwrap()
+            .into_iter()
+            .map(|notebook| {
+                (
+                    notebook.name().clone(),
+                    notebook.data_path().clone(),
+                    notebook.journal_count(),
+                    notebook.is_selected(),
+                )
+            })
+            .collect();
+
+    assert_eq!(
+        vec![
+            (
+                "personal".to_owned(),
+                files.path().join("personal"),
+                1,
+                true
+            ),
+            ("work".to_owned(), files.path().join("work"), 2, false),
+        ],
+        actual
+    );
+}
+
+#[test]
+fn should_prefer_selected_notebook_over_given_data_path() {
+    let files = create_notebooks();
+    let mut general = GenerellArgs::new(
+        false,
+        Some(files.path().to_str().unwrap().to_string()),
+        Some(files.path().join("other").to_str().unwrap().to_string()),
+    );
+    general.set_notebook(Some("work".to_owned()));
+    let app_options = AppOptions::with(general);
+
+    let actual = app_options.get_data_path().unwrap();
+
+    assert_eq!(files.path().join("work"), actual);
+}
+
+#[test]
+fn should_list_notebooks_with_their_own_data_path_despite_given_data_path() {
+    let files = create_notebooks();
+    let app_options = AppOptions::with(GenerellArgs::new(
+        false,
+        Some(files.path().to_str().unwrap().to_string()),
+        Some(files.path().join("other").to_str().unwrap().to_string()),
+    ));
+
+    assert_eq!(
+        files.path().join("other"),
+        app_options.get_data_path().unwrap()
+    );
+    let actual: Vec<(PathBuf, usize)> = notebook_actions::list_notebooks(&app_options)
+        .unwrap()
+        .into_iter()
+        .map(|notebook| (notebook.data_path().clone(), notebook.journal_count()))
+        .collect();
+    assert_eq!(
+        vec![
+            (files.path().join("personal"), 1),
+            (files.path().join("work"), 2)
+        ],
+        actual
+    );
+}
+
+fn create_notebooks() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .with_file(PathBuf::from("work/2023_03_09_daily.md"), None)
+        .with_file(PathBuf::from("work/2023_yearly.md"), None)
+        .with_file(PathBuf::from("work/notes.txt"), None)
+        .with_file(PathBuf::from("personal/2023_03_10_daily.md"), None)
+        .build()
+}
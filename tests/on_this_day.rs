@@ -0,0 +1,109 @@
+mod common;
+use std::{fs, path::PathBuf};
+
+use common::{given_date, set_up_options, FileTmpBuilder};
+use daily_ruster_man::{
+    cli::edit_argument::EditCommonArgs,
+    core::{on_this_day_actions, open_actions, process_handling::TestProcessExecuter},
+};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r#"
+daily_template = "+daily.template"
+archive_folders = ["+archive"]
+[[placeholders]]
+key = "past"
+value = "{{ON_THIS_DAY}}"
+"#;
+
+#[test]
+fn should_fetch_same_day_of_previous_years_newest_first() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    let actual = on_this_day_actions::fetch_on_this_day(given_date(), false, &app_options).unwrap();
+
+    let dates: Vec<(String, u32, &str)> = actual
+        .iter()
+        .map(|past| {
+            (
+                past.date().to_string(),
+                past.years_before(given_date()),
+                past.content().as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("2022-04-02".to_owned(), 1, "# Last year\nText"),
+            ("2020-04-02".to_owned(), 3, "# From archive"),
+        ],
+        dates
+    );
+}
+
+#[test]
+fn should_fetch_same_week_of_previous_years() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    let actual = on_this_day_actions::fetch_on_this_day(given_date(), true, &app_options).unwrap();
+
+    let dates: Vec<String> = actual.iter().map(|past| past.date().to_string()).collect();
+    assert_eq!(vec!["2022-04-03", "2022-04-02"], dates);
+}
+
+#[test]
+fn should_insert_links_to_previous_years_into_template() {
+    let files = create_files();
+    let app_options = set_up_options(&files);
+
+    open_actions::open_by_date(
+        &TestProcessExecuter::default(),
+        given_date(),
+        &app_options,
+        &EditCommonArgs::default(),
+    )
+    .unwrap();
+
+    let expected = format!(
+        "# Today\n- [2022-04-02](2022_04_02_daily.md) Last year\n- [2020-04-02]({}) From archive\n",
+        files
+            .path()
+            .join("archive/2020_04_02_daily.md")
+            .to_string_lossy()
+    );
+    assert_eq!(
+        expected,
+        fs::read_to_string(files.path().join("data/2023_04_02_daily.md")).unwrap()
+    );
+}
+
+fn create_files() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some(CONF_FILE_CONTENT.to_owned()),
+        )
+        .with_file(
+            PathBuf::from("daily.template"),
+            Some("# Today\n{past}\n".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2022_04_02_daily.md"),
+            Some("# Last year\nText".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2022_04_03_daily.md"),
+            Some("Same week".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2022_04_20_daily.md"),
+            Some("Other week".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("archive/2020_04_02_daily.md"),
+            Some("# From archive".to_owned()),
+        )
+        .build()
+}
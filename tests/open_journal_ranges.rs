@@ -0,0 +1,218 @@
+mod common;
+use std::fs;
+use std::path::PathBuf;
+
+use age::secrecy::ExposeSecret;
+use common::{set_up_options, FileTmpBuilder};
+use date_validation_types::ValidatedDate;
+use daily_ruster_man::{
+    cli::edit_argument::EditCommonArgs,
+    core::{open_actions, process_handling::TestProcessExecuter},
+};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r#"
+daily_template = "+daily.template"
+archive_folders = ["+archive"]
+"#;
+
+#[test]
+fn should_open_all_days_of_range_in_one_session() {
+    let files = create_journals(CONF_FILE_CONTENT);
+    let app_options = set_up_options(&files);
+    let processor = TestProcessExecuter::default();
+
+    let opened = open_actions::open_days_in_range(
+        &processor,
+        ValidatedDate::from_ymd(2023, 3, 9).unwrap(),
+        ValidatedDate::from_ymd(2023, 3, 11).unwrap(),
+        false,
+        &app_options,
+        &EditCommonArgs::default(),
+        |_| Ok(false),
+    )
+    .unwrap();
+
+    let expected: Vec<PathBuf> = [
+        "2023_03_09_daily.md",
+        "2023_03_10_daily.md",
+        "2023_03_11_daily.md",
+    ]
+    .iter()
+    .map(|name| files.path().join("data").join(name))
+    .collect();
+    assert_eq!(expected, opened);
+    assert_eq!(
+        format!(
+            "vim {} {} {}",
+            expected[0].to_string_lossy(),
+            expected[1].to_string_lossy(),
+            expected[2].to_string_lossy()
+        ),
+        processor.get_last_executed_program()
+    );
+    assert_eq!("Template", fs::read_to_string(&expected[0]).unwrap());
+    assert_eq!("Existing", fs::read_to_string(&expected[1]).unwrap());
+}
+
+#[test]
+fn should_open_only_existing_days_of_range() {
+    let files = create_journals(CONF_FILE_CONTENT);
+    let app_options = set_up_options(&files);
+    let processor = TestProcessExecuter::default();
+
+    let opened = open_actions::open_days_in_range(
+        &processor,
+        ValidatedDate::from_ymd(2023, 3, 9).unwrap(),
+        ValidatedDate::from_ymd(2023, 3, 11).unwrap(),
+        true,
+        &app_options,
+        &EditCommonArgs::default(),
+        |_| Ok(false),
+    )
+    .unwrap();
+
+    assert_eq!(vec![files.path().join("data/2023_03_10_daily.md")], opened);
+    assert!(!files.path().join("data/2023_03_09_daily.md").exists());
+}
+
+#[test]
+fn should_skip_archived_days_without_restore() {
+    let files = create_journals(CONF_FILE_CONTENT);
+    let app_options = set_up_options(&files);
+    let processor = TestProcessExecuter::default();
+
+    let opened = open_actions::open_days_in_range(
+        &processor,
+        ValidatedDate::from_ymd(2022, 1, 5).unwrap(),
+        ValidatedDate::from_ymd(2022, 1, 5).unwrap(),
+        true,
+        &app_options,
+        &EditCommonArgs::default(),
+        |_| Ok(false),
+    )
+    .unwrap();
+
+    assert!(opened.is_empty());
+    assert!(processor.get_last_executed_program().is_empty());
+}
+
+#[test]
+fn should_reject_range_ending_before_its_start() {
+    let files = create_journals(CONF_FILE_CONTENT);
+    let app_options = set_up_options(&files);
+    let processor = TestProcessExecuter::default();
+
+    let actual = open_actions::open_days_in_range(
+        &processor,
+        ValidatedDate::from_ymd(2023, 3, 11).unwrap(),
+        ValidatedDate::from_ymd(2023, 3, 9).unwrap(),
+        false,
+        &app_options,
+        &EditCommonArgs::default(),
+        |_| Ok(false),
+    );
+
+    assert!(actual.is_err());
+    assert!(processor.get_last_executed_program().is_empty());
+}
+
+#[test]
+fn should_keep_encrypted_journals_of_range_encrypted() {
+    let key = age::x25519::Identity::generate();
+    let files = create_journals(
+        r#"
+daily_template = "+daily.template"
+[encryption]
+key_file = "+journal.key"
+"#,
+    );
+    fs::write(
+        files.path().join("journal.key"),
+        key.to_string().expose_secret(),
+    )
+    .unwrap();
+    let app_options = set_up_options(&files);
+    let processor = TestProcessExecuter::default();
+
+    let opened = open_actions::open_days_in_range(
+        &processor,
+        ValidatedDate::from_ymd(2023, 3, 11).unwrap(),
+        ValidatedDate::from_ymd(2023, 3, 12).unwrap(),
+        false,
+        &app_options,
+        &EditCommonArgs::default(),
+        |_| Ok(false),
+    )
+    .unwrap();
+
+    assert_eq!(2, opened.len());
+    let executed = processor.get_last_executed_program();
+    assert!(!executed.contains(files.path().to_string_lossy().as_ref()));
+    assert_eq!(3, executed.split(' ').count());
+    for path in opened {
+        let stored = fs::read(path).unwrap();
+        assert!(!String::from_utf8_lossy(&stored).contains("Template"));
+    }
+}
+
+#[test]
+fn should_refuse_long_range_without_confirmation() {
+    let files = create_journals(CONF_FILE_CONTENT);
+    let app_options = set_up_options(&files);
+    let processor = TestProcessExecuter::default();
+    let mut asked_days = None;
+
+    let actual = open_actions::open_days_in_range(
+        &processor,
+        ValidatedDate::from_ymd(2023, 3, 1).unwrap(),
+        ValidatedDate::from_ymd(2023, 4, 1).unwrap(),
+        false,
+        &app_options,
+        &EditCommonArgs::default(),
+        |days| {
+            asked_days = Some(days);
+            Ok(false)
+        },
+    );
+
+    assert!(actual.is_err());
+    assert_eq!(Some(32), asked_days);
+    assert!(processor.get_last_executed_program().is_empty());
+    assert!(!files.path().join("data/2023_03_01_daily.md").exists());
+}
+
+#[test]
+fn should_open_long_range_of_existing_journals_without_confirmation() {
+    let files = create_journals(CONF_FILE_CONTENT);
+    let app_options = set_up_options(&files);
+    let processor = TestProcessExecuter::default();
+
+    let opened = open_actions::open_days_in_range(
+        &processor,
+        ValidatedDate::from_ymd(2023, 1, 1).unwrap(),
+        ValidatedDate::from_ymd(2023, 12, 31).unwrap(),
+        true,
+        &app_options,
+        &EditCommonArgs::default(),
+        |_| panic!("Confirmation should not be asked for existing journals only"),
+    )
+    .unwrap();
+
+    assert_eq!(vec![files.path().join("data/2023_03_10_daily.md")], opened);
+}
+
+fn create_journals(config: &str) -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(PathBuf::from("config.toml"), Some(config.to_owned()))
+        .with_file(PathBuf::from("daily.template"), Some("Template".to_owned()))
+        .with_file(
+            PathBuf::from("data/2023_03_10_daily.md"),
+            Some("Existing".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("archive/2022_01_05_daily.md"),
+            Some("archived".to_owned()),
+        )
+        .build()
+}
@@ -1,14 +1,22 @@
 mod common;
-use std::{path::PathBuf, fs};
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+};
 
+use age::secrecy::ExposeSecret;
 use date_validation_types::{ValidatedDate, ValidatedYear};
-use common::FileTmpBuilder;
+use common::{given_date, options_with, FileTmpBuilder};
 use daily_ruster_man::{
     core::{
-        open_actions, process_handling::TestProcessExecuter,
-        date_models::open_by::OpenByMonthInYear, app_options::AppOptions,
+        open_actions,
+        process_handling::{ProcessExecuter, TestProcessExecuter},
+        date_models::open_by::OpenByMonthInYear,
+        app_options::AppOptions,
     },
     cli::{app_args::GenerellArgs, edit_argument::EditCommonArgs},
+    AppResult,
 };
 use tempfile::TempDir;
 
@@ -250,3 +258,277 @@ fn assert_open_action(
     assert!(match_on_return);
     assert_eq!(expected_execution, actual_execution);
 }
+
+#[test]
+fn should_store_journal_encrypted_and_show_it_decrypted() {
+    const CONF_FILE_CONTENT: &str = r#"
+      daily_template="+daily.template"
+      [encryption]
+      key_file="+journal.key"
+        "#;
+    const TEMPLATE_FILE_CONTENT: &str = "Some secret template";
+
+    let key = age::x25519::Identity::generate();
+    let (files, app_options) = set_up_with_config(
+        CONF_FILE_CONTENT,
+        FileTmpBuilder::default()
+            .with_file(
+                PathBuf::from("daily.template"),
+                Some(TEMPLATE_FILE_CONTENT.to_owned()),
+            )
+            .with_file(
+                PathBuf::from("journal.key"),
+                Some(key.to_string().expose_secret().to_owned()),
+            ),
+    );
+
+    let processor = TestProcessExecuter::default();
+    let opened = open_actions::open_by_date(
+        &processor,
+        given_date(),
+        &app_options,
+        &EditCommonArgs::default(),
+    );
+    assert!(matches!(opened, Ok(None)));
+
+    let stored = fs::read(files.path().join("2023_04_02_daily.md"))
+        .expect("Encrypted journal should have been created");
+    assert!(!String::from_utf8_lossy(&stored).contains(TEMPLATE_FILE_CONTENT));
+
+    let shown = open_actions::open_by_date(
+        &processor,
+        given_date(),
+        &app_options,
+        &EditCommonArgs::new(None, true),
+    );
+    assert!(matches!(shown, Ok(Some(content)) if content == TEMPLATE_FILE_CONTENT));
+}
+
+#[test]
+fn should_keep_journal_and_decrypted_copy_if_editor_fails() {
+    let (_files, app_options) = set_up_encrypted_journal();
+    let editor = EditorWithAction::new(|copy, _| {
+        fs::write(copy, "Partial edit").unwrap();
+        anyhow::bail!("Editor crashed")
+    });
+
+    let actual = open_actions::open_by_date(
+        &editor,
+        given_date(),
+        &app_options,
+        &EditCommonArgs::default(),
+    );
+
+    assert!(actual.is_err());
+    assert_eq!("Partial edit", fs::read_to_string(editor.copy()).unwrap());
+    let shown = open_actions::open_by_date(
+        &editor,
+        given_date(),
+        &app_options,
+        &EditCommonArgs::new(None, true),
+    );
+    assert!(matches!(shown, Ok(Some(content)) if content == ENCRYPTED_CONTENT));
+}
+
+#[test]
+fn should_keep_decrypted_copy_if_it_can_not_be_encrypted_back() {
+    let (files, app_options) = set_up_encrypted_journal();
+    let journal = files.path().join("2023_04_02_daily.md");
+    let editor = EditorWithAction::new(move |copy, _| {
+        fs::write(copy, "Edited").unwrap();
+        // Writing the encrypted journal back fails if a folder took its place.
+        fs::remove_file(&journal).unwrap();
+        fs::create_dir(&journal).unwrap();
+        Ok(())
+    });
+
+    let actual = open_actions::open_by_date(
+        &editor,
+        given_date(),
+        &app_options,
+        &EditCommonArgs::default(),
+    );
+
+    let error = actual.unwrap_err().to_string();
+    let kept_folder = editor.copy().parent().unwrap().parent().unwrap().to_owned();
+    assert!(error.contains(&format!("{:?}", kept_folder)));
+    assert_eq!("Edited", fs::read_to_string(editor.copy()).unwrap());
+    fs::remove_dir_all(kept_folder).unwrap();
+}
+
+#[test]
+fn should_open_daily_journal_with_configured_extension() {
+    const CONF_FILE_CONTENT: &str = r#"
+      daily_extension = "org"
+        "#;
+    let processor = TestProcessExecuter::default();
+    let (files, app_options) =
+        set_up_with_config(CONF_FILE_CONTENT, &mut FileTmpBuilder::default());
+
+    let edit_option = EditCommonArgs::default();
+    let actual = open_actions::open_by_date(&processor, given_date(), &app_options, &edit_option);
+
+    assert_open_action(
+        processor,
+        files,
+        matches!(actual, Ok(None)),
+        "2023_04_02_daily.org".into(),
+        "vim",
+    );
+}
+
+#[test]
+fn should_open_existing_daily_journal_with_other_extension() {
+    const CONF_FILE_CONTENT: &str = r#"
+      daily_extension = "org"
+        "#;
+    let processor = TestProcessExecuter::default();
+    let (files, app_options) = set_up_with_config(
+        CONF_FILE_CONTENT,
+        FileTmpBuilder::default().with_file(PathBuf::from("2023_04_02_daily.txt"), None),
+    );
+
+    let edit_option = EditCommonArgs::default();
+    let actual = open_actions::open_by_date(&processor, given_date(), &app_options, &edit_option);
+
+    assert_open_action(
+        processor,
+        files,
+        matches!(actual, Ok(None)),
+        "2023_04_02_daily.txt".into(),
+        "vim",
+    );
+}
+
+#[test]
+fn should_open_daily_journal_in_folder_of_data_layout() {
+    const CONF_FILE_CONTENT: &str = r#"
+      data_layout = "{year}/{month}"
+        "#;
+    let processor = TestProcessExecuter::default();
+    let (files, app_options) =
+        set_up_with_config(CONF_FILE_CONTENT, &mut FileTmpBuilder::default());
+
+    let edit_option = EditCommonArgs::default();
+    let actual = open_actions::open_by_date(&processor, given_date(), &app_options, &edit_option);
+
+    assert!(files.path().join("2023/04").is_dir());
+    assert_open_action(
+        processor,
+        files,
+        matches!(actual, Ok(None)),
+        "2023/04/2023_04_02_daily.md".into(),
+        "vim",
+    );
+}
+
+#[test]
+fn should_open_existing_yearly_journal_outside_of_data_layout() {
+    const CONF_FILE_CONTENT: &str = r#"
+      data_layout = "{year}/{month}"
+        "#;
+    let processor = TestProcessExecuter::default();
+    let (files, app_options) = set_up_with_config(
+        CONF_FILE_CONTENT,
+        FileTmpBuilder::default().with_file(PathBuf::from("2022_yearly.md"), None),
+    );
+
+    let edit_option = EditCommonArgs::default();
+    let actual = open_actions::open_by_year(
+        &processor,
+        2022.try_into().unwrap(),
+        &app_options,
+        &edit_option,
+    );
+
+    assert!(!files.path().join("2022").exists());
+    assert_open_action(
+        processor,
+        files,
+        matches!(actual, Ok(None)),
+        "2022_yearly.md".into(),
+        "vim",
+    );
+}
+
+#[test]
+fn should_open_daily_journal_with_configured_naming_pattern() {
+    const CONF_FILE_CONTENT: &str = r#"
+      daily_name_pattern = "{yyyy}-{mm}-{dd}.{ext}"
+      daily_extension = "org"
+        "#;
+    let processor = TestProcessExecuter::default();
+    let (files, app_options) =
+        set_up_with_config(CONF_FILE_CONTENT, &mut FileTmpBuilder::default());
+
+    let edit_option = EditCommonArgs::default();
+    let actual = open_actions::open_by_date(&processor, given_date(), &app_options, &edit_option);
+
+    assert_open_action(
+        processor,
+        files,
+        matches!(actual, Ok(None)),
+        "2023-04-02.org".into(),
+        "vim",
+    );
+}
+
+/// Creates the given files and a config file with the given content.
+/// The temporary folder is used as config and data folder.
+fn set_up_with_config(config: &str, files: &mut FileTmpBuilder) -> (TempDir, AppOptions) {
+    let files = files
+        .with_file(PathBuf::from("config.toml"), Some(config.to_owned()))
+        .build();
+    let app_options = options_with(Some(files.path()), Some(files.path()));
+    (files, app_options)
+}
+
+const ENCRYPTED_CONTENT: &str = "Some secret";
+
+fn set_up_encrypted_journal() -> (TempDir, AppOptions) {
+    let key = age::x25519::Identity::generate();
+    let (files, app_options) = set_up_with_config(
+        "[encryption]\nkey_file=\"+journal.key\"",
+        FileTmpBuilder::default().with_file(
+            PathBuf::from("journal.key"),
+            Some(key.to_string().expose_secret().to_owned()),
+        ),
+    );
+    let path = files.path().join("2023_04_02_daily.md");
+    let encrypted = age::encrypt(&key.to_public(), ENCRYPTED_CONTENT.as_bytes()).unwrap();
+    fs::write(path, encrypted).unwrap();
+
+    (files, app_options)
+}
+
+/// Editor which runs the given action on the first opened path instead of starting a program.
+struct EditorWithAction<F> {
+    action: F,
+    opened: RefCell<Vec<PathBuf>>,
+}
+
+impl<F> EditorWithAction<F>
+where
+    F: Fn(&Path, &AppOptions) -> AppResult,
+{
+    fn new(action: F) -> Self {
+        Self {
+            action,
+            opened: RefCell::default(),
+        }
+    }
+
+    fn copy(&self) -> PathBuf {
+        self.opened.borrow()[0].clone()
+    }
+}
+
+impl<F> ProcessExecuter for EditorWithAction<F>
+where
+    F: Fn(&Path, &AppOptions) -> AppResult,
+{
+    fn start_program(&self, option: &AppOptions, _editor: &str, paths: &[&Path]) -> AppResult {
+        *self.opened.borrow_mut() = paths.iter().map(|path| path.to_path_buf()).collect();
+        (self.action)(paths[0], option)
+    }
+}
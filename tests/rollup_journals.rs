@@ -0,0 +1,114 @@
+mod common;
+use std::{fs, path::PathBuf};
+
+use common::{set_up_options, FileTmpBuilder};
+use date_validation_types::ValidatedYear;
+use daily_ruster_man::{
+    cli::edit_argument::EditCommonArgs,
+    core::{
+        date_models::open_by::OpenByMonthInYear, open_actions,
+        process_handling::TestProcessExecuter,
+    },
+};
+use tempfile::TempDir;
+
+const CONF_FILE_CONTENT: &str = r#"
+monthly_template = "+monthly.template"
+yearly_template = "+yearly.template"
+archive_folders = ["+archive"]
+[[placeholders]]
+key = "days"
+value = "{{MONTH_ROLLUP}}"
+[[placeholders]]
+key = "months"
+value = "{{YEAR_ROLLUP}}"
+"#;
+
+#[test]
+fn should_insert_dailies_of_month_into_monthly_template() {
+    let files = create_files(CONF_FILE_CONTENT);
+    let app_options = set_up_options(&files);
+
+    open_actions::open_by_month_year(
+        &TestProcessExecuter::default(),
+        OpenByMonthInYear::WithYear {
+            month: 3.try_into().unwrap(),
+            year: 2023.try_into().unwrap(),
+        },
+        &app_options,
+        &EditCommonArgs::default(),
+    )
+    .unwrap();
+
+    let expected = format!(
+        "# March\n- [2023-03-02]({}) Archived day\n- [2023-03-10](2023_03_10_daily.md) Release day\n",
+        files
+            .path()
+            .join("archive/2023_03_02_daily.md")
+            .to_string_lossy()
+    );
+    assert_eq!(
+        expected,
+        fs::read_to_string(files.path().join("data/2023_03_monthly.md")).unwrap()
+    );
+}
+
+#[test]
+fn should_insert_configured_section_of_monthlies_into_yearly_template() {
+    let config = format!("rollup_section = \"## Summary\"\n{}", CONF_FILE_CONTENT);
+    let files = create_files(&config);
+    let app_options = set_up_options(&files);
+    let year: ValidatedYear = 2023.try_into().unwrap();
+
+    open_actions::open_by_year(
+        &TestProcessExecuter::default(),
+        year,
+        &app_options,
+        &EditCommonArgs::default(),
+    )
+    .unwrap();
+
+    let expected = "# 2023\n- [2023-01](2023_01_monthly.md) January\n- [2023-02](2023_02_monthly.md)\n  Moved to the new flat\n";
+    assert_eq!(
+        expected,
+        fs::read_to_string(files.path().join("data/2023_yearly.md")).unwrap()
+    );
+}
+
+fn create_files(config: &str) -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(PathBuf::from("config.toml"), Some(config.to_owned()))
+        .with_file(
+            PathBuf::from("monthly.template"),
+            Some("# March\n{days}\n".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("yearly.template"),
+            Some("# 2023\n{months}\n".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_03_10_daily.md"),
+            Some("Notes\n# Release day\n## Summary\nShipped".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_04_01_daily.md"),
+            Some("# Other month".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("archive/2023_03_02_daily.md"),
+            Some("# Archived day".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_01_monthly.md"),
+            Some("# January\n## Goals".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2023_02_monthly.md"),
+            Some("# February\n## Summary\n\nMoved to the new flat\n\n## Goals".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("data/2022_12_monthly.md"),
+            Some("# Other year".to_owned()),
+        )
+        .build()
+}
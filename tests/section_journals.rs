@@ -0,0 +1,101 @@
+mod common;
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use common::{options_with, FileTmpBuilder};
+use daily_ruster_man::core::{
+    date_models::{
+        find_by::{FindByRange, FindByYearMonthDay},
+        JournalKind,
+    },
+    section_actions,
+};
+use tempfile::TempDir;
+
+#[test]
+fn should_extract_section_of_journals_in_range() {
+    let files = create_files();
+    let app_options = options_with(None, Some(files.path()));
+
+    let actual = section_actions::fetch_sections(
+        "## Blockers",
+        &[JournalKind::Monthly, JournalKind::Daily],
+        &FindByRange::new(date(2023, 3, 6), date(2023, 3, 12)),
+        &FindByYearMonthDay::new(None, None, None).unwrap(),
+        &app_options,
+    )
+    .unwrap();
+
+    let sections: Vec<(&str, JournalKind, &str)> = actual
+        .iter()
+        .map(|section| {
+            (
+                section.date().as_str(),
+                section.kind(),
+                section.content().as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("2023-03", JournalKind::Monthly, "- hiring"),
+            ("2023-03-06", JournalKind::Daily, "- slow build"),
+            ("2023-03-08", JournalKind::Daily, "- flaky tests\n- review"),
+        ],
+        sections
+    );
+}
+
+#[test]
+fn should_match_title_without_marks_regardless_of_case() {
+    let files = create_files();
+    let app_options = options_with(None, Some(files.path()));
+
+    let actual = section_actions::fetch_sections(
+        "gratitude",
+        &[JournalKind::Daily],
+        &FindByRange::new(None, None),
+        &FindByYearMonthDay::new(None, None, None).unwrap(),
+        &app_options,
+    )
+    .unwrap();
+
+    let dates: Vec<&str> = actual
+        .iter()
+        .map(|section| section.date().as_str())
+        .collect();
+    assert_eq!(vec!["2023-03-06", "2023-03-20"], dates);
+}
+
+fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+fn create_files() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("2023_03_06_daily.md"),
+            Some("# Day\n## Gratitude\nSun\n## Blockers\n- slow build\n".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("2023_03_07_daily.md"),
+            Some("# Day\n## Blockers\n\n## Notes\n- empty blockers".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("2023_03_08_daily.md"),
+            Some("# Day\n## Blockers\n- flaky tests\n- review\n\n## Notes".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("2023_03_09_daily.md"),
+            Some("# Day without blockers".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("2023_03_20_daily.md"),
+            Some("# Day\n### GRATITUDE\nRain\n## Blockers\n- out of range".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("2023_03_monthly.md"),
+            Some("# March\n## Blockers\n- hiring".to_owned()),
+        )
+        .build()
+}
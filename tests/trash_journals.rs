@@ -0,0 +1,172 @@
+mod common;
+use std::path::PathBuf;
+
+use daily_ruster_man::core::app_options::AppOptions;
+use daily_ruster_man::core::delete_actions::{self, DeletionResult};
+use daily_ruster_man::core::trash_actions::{self, PurgeResult, RestoreResult};
+use daily_ruster_man::cli::{app_args::GenerellArgs, deletion_arguments::CommonDeleteArg};
+
+use date_validation_types::{ValidatedDate, ValidatedYear};
+use tempfile::TempDir;
+
+#[test]
+fn should_list_deleted_journal_in_trash() {
+    let (files, app_options) = set_up();
+
+    delete_yearly_2022(&app_options);
+
+    let trashed =
+        trash_actions::fetch_trashed_journals(&app_options).expect("Could not list the trash");
+    let names: Vec<&str> = trashed
+        .iter()
+        .map(|journal| journal.original_name())
+        .collect();
+
+    assert_eq!(vec!["2022_yearly.md"], names);
+    assert!(!files.path().join("2022_yearly.md").exists());
+}
+
+#[test]
+fn should_list_empty_trash_without_creating_it() {
+    let (files, app_options) = set_up();
+
+    let trashed =
+        trash_actions::fetch_trashed_journals(&app_options).expect("Could not list the trash");
+
+    assert!(trashed.is_empty());
+    assert!(!files.path().join(".trash").exists());
+}
+
+#[test]
+fn should_restore_deleted_journal() {
+    let (files, app_options) = set_up();
+
+    delete_yearly_2022(&app_options);
+
+    let actual = trash_actions::restore_from_trash("2022_yearly.md", &app_options)
+        .expect("Could not restore journal");
+
+    let expected_path = files.path().join("2022_yearly.md");
+    assert!(matches!(actual, RestoreResult::Restored(at) if at == expected_path));
+    assert!(expected_path.exists());
+
+    let trashed =
+        trash_actions::fetch_trashed_journals(&app_options).expect("Could not list the trash");
+    assert!(trashed.is_empty());
+}
+
+#[test]
+fn should_keep_journals_with_same_name_deleted_in_same_second() {
+    let (files, app_options) = set_up();
+
+    let first_trashed = delete_yearly_2022(&app_options);
+    std::fs::write(files.path().join("2022_yearly.md"), "Second one").unwrap();
+    let second_trashed = delete_yearly_2022(&app_options);
+
+    assert_ne!(first_trashed, second_trashed);
+    assert!(first_trashed.exists());
+    assert!(second_trashed.exists());
+    let trashed =
+        trash_actions::fetch_trashed_journals(&app_options).expect("Could not list the trash");
+    assert_eq!(2, trashed.len());
+}
+
+#[test]
+fn should_return_not_in_trash_for_restore() {
+    let (_files, app_options) = set_up();
+
+    let actual = trash_actions::restore_from_trash("2022_yearly.md", &app_options)
+        .expect("Could not restore journal");
+
+    assert_eq!(RestoreResult::NotInTrash, actual);
+}
+
+#[test]
+fn should_purge_trash() {
+    let (_files, app_options) = set_up();
+
+    let trashed_at = delete_yearly_2022(&app_options);
+
+    let actual = trash_actions::purge_trash(
+        None,
+        &CommonDeleteArg::new(true, false),
+        &app_options,
+        |_| Ok(true),
+    )
+    .expect("Could not purge the trash");
+
+    assert_eq!(PurgeResult::Purged(1), actual);
+    assert!(!trashed_at.exists());
+}
+
+#[test]
+fn should_keep_recently_deleted_for_purge_older_than() {
+    let (_files, app_options) = set_up();
+
+    let trashed_at = delete_yearly_2022(&app_options);
+
+    let actual = trash_actions::purge_trash(
+        Some(1),
+        &CommonDeleteArg::new(true, false),
+        &app_options,
+        |_| Ok(true),
+    )
+    .expect("Could not purge the trash");
+
+    assert_eq!(PurgeResult::Purged(0), actual);
+    assert!(trashed_at.exists());
+}
+
+#[test]
+fn should_restore_deleted_journal_into_its_sub_folder() {
+    let files = common::FileTmpBuilder::default()
+        .with_file(PathBuf::from("2023/03/2023_03_09_daily.md"), None)
+        .build();
+    let app_options = AppOptions::with(GenerellArgs::new(
+        false,
+        None,
+        Some(files.path().to_str().unwrap().to_string()),
+    ));
+    let date = ValidatedDate::new(
+        2023.try_into().unwrap(),
+        3.try_into().unwrap(),
+        9.try_into().unwrap(),
+    )
+    .unwrap();
+
+    let deleted =
+        delete_actions::delete_day_journal(date, &CommonDeleteArg::new(true, false), &app_options)
+            .expect("Could not delete journal");
+    assert!(matches!(deleted, DeletionResult::Deleted { .. }));
+    std::fs::remove_dir_all(files.path().join("2023")).unwrap();
+
+    let actual = trash_actions::restore_from_trash("2023_03_09_daily.md", &app_options)
+        .expect("Could not restore journal");
+
+    let expected_path = files.path().join("2023/03/2023_03_09_daily.md");
+    assert!(matches!(actual, RestoreResult::Restored(at) if at == expected_path));
+    assert!(expected_path.exists());
+}
+
+fn delete_yearly_2022(app_options: &AppOptions) -> PathBuf {
+    let year: ValidatedYear = 2022.try_into().unwrap();
+    let result =
+        delete_actions::delete_year_journal(year, &CommonDeleteArg::new(true, false), app_options)
+            .expect("Could not delete journal");
+
+    match result {
+        DeletionResult::Deleted { trashed_at } => trashed_at,
+        _ => panic!("Precondition: journal should have been deleted"),
+    }
+}
+
+fn set_up() -> (TempDir, AppOptions) {
+    let files = common::create_sample_data_folder();
+    let general = GenerellArgs::new(
+        false,
+        None,
+        Some(files.path().to_str().unwrap().to_string()),
+    );
+
+    (files, AppOptions::with(general))
+}
@@ -0,0 +1,134 @@
+mod common;
+use std::{fs, path::PathBuf};
+
+use common::{given_date, set_up_options, FileTmpBuilder};
+use daily_ruster_man::core::{
+    date_models::JournalKind,
+    write_actions::{self, WriteMode, WriteResult},
+};
+use tempfile::TempDir;
+
+#[test]
+fn should_write_new_journal_without_template() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some("daily_template = \"+daily.template\"".to_owned()),
+        )
+        .with_file(
+            PathBuf::from("daily.template"),
+            Some("# Template\n".to_owned()),
+        )
+        .build();
+    let app_options = set_up_options(&files);
+
+    let actual = write_actions::write_to_journal(
+        given_date(),
+        JournalKind::Daily,
+        "# Meeting notes\n",
+        WriteMode::Replace,
+        false,
+        &app_options,
+    )
+    .unwrap();
+
+    let expected_path = files.path().join("data/2023_04_02_daily.md");
+    assert_eq!(WriteResult::Written(expected_path.clone()), actual);
+    assert_eq!(
+        "# Meeting notes\n",
+        fs::read_to_string(expected_path).unwrap()
+    );
+}
+
+#[test]
+fn should_not_replace_journal_with_content_without_force() {
+    let files = create_with_existing_monthly();
+    let app_options = set_up_options(&files);
+    let path = files.path().join("data/2023_04_monthly.md");
+
+    let refused = write_actions::write_to_journal(
+        given_date(),
+        JournalKind::Monthly,
+        "new",
+        WriteMode::Replace,
+        false,
+        &app_options,
+    )
+    .unwrap();
+    assert_eq!(WriteResult::NotOverwritten(path.clone()), refused);
+    assert_eq!("old\n", fs::read_to_string(&path).unwrap());
+
+    let forced = write_actions::write_to_journal(
+        given_date(),
+        JournalKind::Monthly,
+        "new",
+        WriteMode::Replace,
+        true,
+        &app_options,
+    )
+    .unwrap();
+    assert_eq!(WriteResult::Written(path.clone()), forced);
+    assert_eq!("new", fs::read_to_string(&path).unwrap());
+}
+
+#[test]
+fn should_append_and_prepend_to_existing_journal() {
+    let files = create_with_existing_monthly();
+    let app_options = set_up_options(&files);
+
+    for (content, mode) in [
+        ("appended\n", WriteMode::Append),
+        ("prepended", WriteMode::Prepend),
+    ] {
+        write_actions::write_to_journal(
+            given_date(),
+            JournalKind::Monthly,
+            content,
+            mode,
+            false,
+            &app_options,
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        "prepended\nold\nappended\n",
+        fs::read_to_string(files.path().join("data/2023_04_monthly.md")).unwrap()
+    );
+}
+
+#[test]
+fn should_append_to_template_of_new_yearly() {
+    let files = FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("config.toml"),
+            Some("yearly_template = \"+yearly.template\"".to_owned()),
+        )
+        .with_file(PathBuf::from("yearly.template"), Some("# Year".to_owned()))
+        .build();
+    let app_options = set_up_options(&files);
+
+    write_actions::write_to_journal(
+        given_date(),
+        JournalKind::Yearly,
+        "goal",
+        WriteMode::Append,
+        false,
+        &app_options,
+    )
+    .unwrap();
+
+    assert_eq!(
+        "# Year\ngoal\n",
+        fs::read_to_string(files.path().join("data/2023_yearly.md")).unwrap()
+    );
+}
+
+fn create_with_existing_monthly() -> TempDir {
+    FileTmpBuilder::default()
+        .with_file(
+            PathBuf::from("data/2023_04_monthly.md"),
+            Some("old\n".to_owned()),
+        )
+        .build()
+}